@@ -0,0 +1,22 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use material_rs::hct::{batch::hct_from_argb_slice, HCT};
+
+/**
+ * A spread of pixels standing in for an image being quantized.
+ */
+fn pixels() -> Vec<u32> {
+    (0..4096u32).map(|i| 0xff000000 | i.wrapping_mul(2654435761) >> 8).collect()
+}
+
+fn batch(c: &mut Criterion) {
+    let pixels = pixels();
+    let mut group = c.benchmark_group("hct_from_argb");
+    group.bench_function("slice", |b| b.iter(|| hct_from_argb_slice(black_box(&pixels))));
+    group.bench_function("each", |b| {
+        b.iter(|| black_box(&pixels).iter().map(|argb| HCT::from(*argb)).collect::<Vec<_>>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, batch);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use material_rs::{
+    hct::cam16::Cam16,
+    utils::color::{delinearized, delinearized_exact, linearized, linearized_exact, xyz_from_argb},
+};
+
+/**
+ * A spread of pixels standing in for an image being quantized.
+ */
+fn pixels() -> Vec<u32> {
+    (0..4096u32).map(|i| 0xff000000 | i.wrapping_mul(2654435761) >> 8).collect()
+}
+
+fn linearize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("linearized");
+    group.bench_function("table", |b| {
+        b.iter(|| (0..=255).map(|i| linearized(black_box(i))).sum::<f64>())
+    });
+    group.bench_function("powf", |b| {
+        b.iter(|| (0..=255).map(|i| linearized_exact(black_box(i))).sum::<f64>())
+    });
+    group.finish();
+
+    let linear: Vec<f64> = (0..=1000).map(|i| i as f64 / 10.0).collect();
+    let mut group = c.benchmark_group("delinearized");
+    group.bench_function("search", |b| {
+        b.iter(|| linear.iter().map(|l| delinearized(black_box(*l)) as u32).sum::<u32>())
+    });
+    group.bench_function("powf", |b| {
+        b.iter(|| linear.iter().map(|l| delinearized_exact(black_box(*l)) as u32).sum::<u32>())
+    });
+    group.finish();
+}
+
+fn convert(c: &mut Criterion) {
+    let pixels = pixels();
+    c.bench_function("xyz_from_argb", |b| {
+        b.iter(|| pixels.iter().map(|argb| xyz_from_argb(black_box(*argb))[1]).sum::<f64>())
+    });
+    c.bench_function("cam16_from_argb", |b| {
+        b.iter(|| pixels.iter().map(|argb| Cam16::from(black_box(*argb)).hue).sum::<f64>())
+    });
+}
+
+criterion_group!(benches, linearize, convert);
+criterion_main!(benches);
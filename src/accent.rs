@@ -0,0 +1,27 @@
+//! Accent colors at conventional hues, shared by the terminal and Base16
+//! palettes.
+
+use crate::{blend::harmonize, hct::HCT};
+
+// Pure sRGB colors. Their hues are where accents start before being
+// harmonized.
+pub(crate) const RED: u32 = 0xffff0000;
+pub(crate) const ORANGE: u32 = 0xffff8000;
+pub(crate) const YELLOW: u32 = 0xffffff00;
+pub(crate) const GREEN: u32 = 0xff00ff00;
+pub(crate) const CYAN: u32 = 0xff00ffff;
+pub(crate) const BLUE: u32 = 0xff0000ff;
+pub(crate) const MAGENTA: u32 = 0xffff00ff;
+
+/**
+ * Chroma accents are placed at, before gamut mapping.
+ */
+pub(crate) const ACCENT_CHROMA: f64 = 60.0;
+
+/**
+ * The hue of `canonical` at the given chroma and tone, harmonized towards
+ * `toward`.
+ */
+pub(crate) fn harmonized_accent(canonical: u32, chroma: f64, tone: f64, toward: u32) -> u32 {
+    harmonize(HCT::new(HCT::from(canonical).hue(), chroma, tone).argb(), toward)
+}
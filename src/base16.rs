@@ -0,0 +1,139 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{
+    accent::{harmonized_accent, ACCENT_CHROMA, BLUE, CYAN, GREEN, MAGENTA, ORANGE, RED, YELLOW},
+    palette::CorePalette,
+    utils::string::{escape_double_quoted, hex_from_argb},
+};
+
+/**
+ * The accents of base08 through base0E.
+ */
+const CANONICAL: [u32; 7] = [RED, ORANGE, YELLOW, GREEN, CYAN, BLUE, MAGENTA];
+
+/**
+ * The bright accents of base12 through base17. Base24 has no bright orange.
+ */
+const BRIGHT: [u32; 6] = [RED, YELLOW, GREEN, CYAN, BLUE, MAGENTA];
+
+/**
+ * Chroma of base0F, which Base16 uses for deprecated and embedded code and
+ * schemes usually make a brown: a muted orange.
+ */
+const BROWN_CHROMA: f64 = 30.0;
+
+/**
+ * Tones each Base24 color is placed at, per variant.
+ */
+struct Tones {
+    /// base00 through base07, from the background to the brightest
+    /// foreground.
+    neutrals: [f64; 8],
+    /// base10 and base11, the darker backgrounds.
+    backgrounds: [f64; 2],
+    accent: f64,
+    brown: f64,
+    bright: f64,
+}
+
+const DARK: Tones = Tones {
+    neutrals: [10.0, 17.0, 24.0, 50.0, 70.0, 90.0, 95.0, 99.0],
+    backgrounds: [6.0, 4.0],
+    accent: 70.0,
+    brown: 50.0,
+    bright: 80.0,
+};
+
+const LIGHT: Tones = Tones {
+    neutrals: [98.0, 94.0, 90.0, 60.0, 40.0, 10.0, 6.0, 4.0],
+    backgrounds: [99.0, 100.0],
+    accent: 40.0,
+    brown: 30.0,
+    bright: 50.0,
+};
+
+/**
+ * A Base24 palette, of which the first 16 colors are the Base16 palette.
+ * base00 through base07 step from the background to the foreground through
+ * the neutral palettes. base08 through base0F are the usual Base16 accent
+ * hues, placed at a fixed tone and chroma and harmonized towards the key
+ * color of the primary palette, and base12 through base17 are their bright
+ * versions.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Base24 {
+    pub dark: bool,
+    /// base00 through base17.
+    pub colors: [u32; 24],
+}
+
+impl Base24 {
+    pub fn dark(seed: u32) -> Self {
+        Self::dark_from_core_palette(&CorePalette::of(seed))
+    }
+
+    pub fn light(seed: u32) -> Self {
+        Self::light_from_core_palette(&CorePalette::of(seed))
+    }
+
+    pub fn dark_from_core_palette(core: &CorePalette) -> Self {
+        Self::new(core, true, &DARK)
+    }
+
+    pub fn light_from_core_palette(core: &CorePalette) -> Self {
+        Self::new(core, false, &LIGHT)
+    }
+
+    fn new(core: &CorePalette, dark: bool, tones: &Tones) -> Self {
+        let key = core.a1.key_color().argb();
+        let mut colors = [0; 24];
+        for (i, tone) in tones.neutrals.into_iter().enumerate() {
+            // The selection and comment colors take the slight tint of the
+            // second neutral palette, like bright black in a terminal.
+            let neutral = if i == 2 || i == 3 { &core.n2 } else { &core.n1 };
+            colors[i] = neutral.tone(tone);
+        }
+        for (i, canonical) in CANONICAL.into_iter().enumerate() {
+            colors[8 + i] = harmonized_accent(canonical, ACCENT_CHROMA, tones.accent, key);
+        }
+        colors[0x0f] = harmonized_accent(ORANGE, BROWN_CHROMA, tones.brown, key);
+        colors[0x10] = core.n1.tone(tones.backgrounds[0]);
+        colors[0x11] = core.n1.tone(tones.backgrounds[1]);
+        for (i, canonical) in BRIGHT.into_iter().enumerate() {
+            colors[0x12 + i] = harmonized_accent(canonical, ACCENT_CHROMA, tones.bright, key);
+        }
+        Self { dark, colors }
+    }
+
+    pub fn base16(&self) -> &[u32] {
+        &self.colors[..16]
+    }
+
+    /**
+     * A Base16 scheme file, following the tinted-theming styling spec.
+     */
+    pub fn base16_yaml(&self, name: &str, author: &str) -> String {
+        self.yaml("base16", name, author, self.base16())
+    }
+
+    /**
+     * A Base24 scheme file, following the tinted-theming styling spec.
+     */
+    pub fn base24_yaml(&self, name: &str, author: &str) -> String {
+        self.yaml("base24", name, author, &self.colors)
+    }
+
+    fn yaml(&self, system: &str, name: &str, author: &str, colors: &[u32]) -> String {
+        let mut yaml = String::new();
+        let _ = writeln!(yaml, "system: \"{system}\"");
+        let _ = writeln!(yaml, "name: \"{}\"", escape_double_quoted(name));
+        let _ = writeln!(yaml, "author: \"{}\"", escape_double_quoted(author));
+        let _ = writeln!(yaml, "variant: \"{}\"", if self.dark { "dark" } else { "light" });
+        let _ = writeln!(yaml, "palette:");
+        for (i, argb) in colors.iter().enumerate() {
+            let _ = writeln!(yaml, "  base{i:02X}: \"{}\"", hex_from_argb(*argb));
+        }
+        yaml
+    }
+}
@@ -1,21 +1,35 @@
-use crate::{hct::{HCT, cam16::{Cam16, UCS}}, utils::{math::{difference_degrees, sanitize_degrees_double, rotation_direction}, color::lstar_from_argb}};
+use crate::{hct::{HCT, cam16::{Cam16, UCS}}, utils::{math::{difference_degrees, sanitize_degrees_double, rotation_direction, lerp}, color::{lstar_from_argb, alpha_from_argb, argb_with_alpha, argb_from_rgb, red_from_argb, green_from_argb, blue_from_argb, linearized, delinearized}}};
+import_float!();
 
+/**
+ * Rotates the hue of `design_color` towards `source_color`, keeping the
+ * alpha of `design_color`.
+ */
 pub fn harmonize(design_color: u32, source_color: u32) -> u32 {
-    let from_hct: HCT = design_color.into();
+    let mut from_hct: HCT = design_color.into();
     let to_hct: HCT = source_color.into();
     let difference_degrees = difference_degrees(from_hct.hue(), to_hct.hue());
     let rotation_degrees = (difference_degrees * 0.5).min(15.0);
     let output_hue = sanitize_degrees_double(
         from_hct.hue() + rotation_degrees * rotation_direction(from_hct.hue(), to_hct.hue())
     );
-    HCT::new(output_hue, from_hct.chroma(), from_hct.tone()).argb()
+    from_hct.set_hue(output_hue);
+    from_hct.argb()
 }
 
+/**
+ * Blends the hue of `from` towards `to`, keeping the chroma and tone of
+ * `from`. Alpha is interpolated linearly by the same amount.
+ */
 pub fn hct_hue(from: u32, to: u32, amount: f64) -> u32 {
     let ucs = cam16_ucs(from, to, amount);
     let ucs_cam: Cam16 = ucs.into();
     let from_cam: Cam16 = from.into();
-    HCT::new(ucs_cam.hue, from_cam.chroma, lstar_from_argb(from)).argb()
+    let alpha = lerp(alpha_from_argb(from) as f64, alpha_from_argb(to) as f64, amount);
+    argb_with_alpha(
+        HCT::new(ucs_cam.hue, from_cam.chroma, lstar_from_argb(from)).argb(),
+        alpha.round() as u32,
+    )
 }
 
 pub fn cam16_ucs(from: u32, to: u32, amount: f64) -> UCS {
@@ -23,6 +37,92 @@ pub fn cam16_ucs(from: u32, to: u32, amount: f64) -> UCS {
     let to: Cam16 = to.into();
     let jstar = from.jstar + (to.jstar - from.jstar) * amount;
     let astar = from.astar + (to.astar - from.astar) * amount;
-    let bstar = from.bstar * (to.bstar - from.bstar) * amount;
+    let bstar = from.bstar + (to.bstar - from.bstar) * amount;
     (jstar, astar, bstar).into()
-}
\ No newline at end of file
+}
+
+/**
+ * The space color channels are mixed in when compositing. Platforms
+ * traditionally blend gamma encoded sRGB, while linear blending is
+ * physically correct.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlendSpace {
+    Srgb,
+    Linear,
+}
+
+/**
+ * Composites `foreground` over `background` with the Porter-Duff
+ * source-over operator, respecting the alpha of both.
+ */
+pub fn source_over(foreground: u32, background: u32, space: BlendSpace) -> u32 {
+    let fa = alpha_from_argb(foreground) as f64 / 255.0;
+    let ba = alpha_from_argb(background) as f64 / 255.0;
+    let alpha = fa + ba * (1.0 - fa);
+    if alpha == 0.0 {
+        return 0;
+    }
+    let channel = |f: u32, b: u32| match space {
+        BlendSpace::Srgb => ((f as f64 * fa + b as f64 * ba * (1.0 - fa)) / alpha).round() as u8,
+        BlendSpace::Linear => delinearized((linearized(f) * fa + linearized(b) * ba * (1.0 - fa)) / alpha),
+    };
+    argb_with_alpha(
+        argb_from_rgb(
+            channel(red_from_argb(foreground), red_from_argb(background)),
+            channel(green_from_argb(foreground), green_from_argb(background)),
+            channel(blue_from_argb(foreground), blue_from_argb(background)),
+        ),
+        (alpha * 255.0).round() as u32,
+    )
+}
+
+/**
+ * Composites `color` over `background` after scaling its alpha by `opacity`.
+ */
+pub fn overlay(color: u32, opacity: f64, background: u32, space: BlendSpace) -> u32 {
+    let alpha = alpha_from_argb(color) as f64 * opacity.clamp(0.0, 1.0);
+    source_over(argb_with_alpha(color, alpha.round() as u32), background, space)
+}
+
+/**
+ * Interaction states of Material components. Each state draws the content
+ * color over the container at a fixed opacity.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum State {
+    Hover,
+    Focus,
+    Pressed,
+    Dragged,
+}
+
+impl State {
+    pub fn opacity(&self) -> f64 {
+        match self {
+            State::Hover => 0.08,
+            State::Focus => 0.10,
+            State::Pressed => 0.10,
+            State::Dragged => 0.16,
+        }
+    }
+}
+
+pub fn state_layer(content: u32, container: u32, state: State, space: BlendSpace) -> u32 {
+    overlay(content, state.opacity(), container, space)
+}
+
+/**
+ * Opacity of the surface tint at each of the Material elevation levels 0
+ * through 5.
+ */
+pub const ELEVATION_TINT_OPACITIES: [f64; 6] = [0.0, 0.05, 0.08, 0.11, 0.12, 0.14];
+
+/**
+ * Tints `surface` with `tint` (usually the primary color) for the given
+ * elevation level. Levels above 5 are treated as 5.
+ */
+pub fn surface_tint(surface: u32, tint: u32, level: usize) -> u32 {
+    let opacity = ELEVATION_TINT_OPACITIES[level.min(ELEVATION_TINT_OPACITIES.len() - 1)];
+    overlay(tint, opacity, surface, BlendSpace::Srgb)
+}
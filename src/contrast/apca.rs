@@ -0,0 +1,131 @@
+use crate::{
+    hct::HCT,
+    palette::TonalPalette,
+    utils::color::{argb_with_alpha, blue_from_argb, green_from_argb, red_from_argb},
+};
+import_float!();
+
+const MAIN_TRC: f64 = 2.4;
+const S_RCO: f64 = 0.2126729;
+const S_GCO: f64 = 0.7151522;
+const S_BCO: f64 = 0.0721750;
+
+const NORM_BG: f64 = 0.56;
+const NORM_TXT: f64 = 0.57;
+const REV_TXT: f64 = 0.62;
+const REV_BG: f64 = 0.65;
+
+const BLK_THRS: f64 = 0.022;
+const BLK_CLMP: f64 = 1.414;
+const SCALE_BOW: f64 = 1.14;
+const SCALE_WOB: f64 = 1.14;
+const LO_BOW_OFFSET: f64 = 0.027;
+const LO_WOB_OFFSET: f64 = 0.027;
+const DELTA_Y_MIN: f64 = 0.0005;
+const LO_CLIP: f64 = 0.1;
+
+/**
+ * Which of text and background is the lighter one. APCA is not symmetric,
+ * so the same pair of colors scores differently depending on polarity.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Polarity {
+    DarkOnLight,
+    LightOnDark,
+}
+
+impl Polarity {
+    pub fn of(text: u32, background: u32) -> Self {
+        if screen_luminance(text) > screen_luminance(background) {
+            Polarity::LightOnDark
+        } else {
+            Polarity::DarkOnLight
+        }
+    }
+}
+
+/**
+ * The estimated screen luminance APCA works with, including the soft clamp
+ * applied near black.
+ */
+pub fn screen_luminance(argb: u32) -> f64 {
+    let channel = |c: u32| (c as f64 / 255.0).powf(MAIN_TRC);
+    let y = S_RCO * channel(red_from_argb(argb))
+        + S_GCO * channel(green_from_argb(argb))
+        + S_BCO * channel(blue_from_argb(argb));
+    if y < BLK_THRS {
+        y + (BLK_THRS - y).powf(BLK_CLMP)
+    } else {
+        y
+    }
+}
+
+/**
+ * The APCA lightness contrast (Lc) of `text` on `background`, following
+ * APCA 0.0.98G-4g. Dark text on a light background scores positive (up to
+ * about 106), light text on a dark background negative (down to about -108).
+ * Lc 75 is the suggested minimum for body text, 60 for other content text
+ * and 45 for large text.
+ */
+pub fn lc(text: u32, background: u32) -> f64 {
+    let y_txt = screen_luminance(text);
+    let y_bg = screen_luminance(background);
+    if (y_bg - y_txt).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+    let output = if y_bg > y_txt {
+        let sapc = (y_bg.powf(NORM_BG) - y_txt.powf(NORM_TXT)) * SCALE_BOW;
+        if sapc < LO_CLIP {
+            0.0
+        } else {
+            sapc - LO_BOW_OFFSET
+        }
+    } else {
+        let sapc = (y_bg.powf(REV_BG) - y_txt.powf(REV_TXT)) * SCALE_WOB;
+        if sapc > -LO_CLIP {
+            0.0
+        } else {
+            sapc + LO_WOB_OFFSET
+        }
+    };
+    output * 100.0
+}
+
+/**
+ * The tone of `palette` closest to the background's lightness whose
+ * magnitude of Lc against `background` reaches `target_lc` with the given
+ * polarity, or `None` if even black or white does not reach it.
+ */
+pub fn tone_for_lc(
+    palette: &TonalPalette,
+    background: u32,
+    target_lc: f64,
+    polarity: Polarity,
+) -> Option<f64> {
+    let target = target_lc.abs();
+    // Solved directly rather than through `palette.tone`, so the search's
+    // fractional probes don't fill the palette's cache.
+    let reaches = |tone: f64| {
+        let text = argb_with_alpha(
+            HCT::new(palette.hue(), palette.chroma(), tone).argb(),
+            palette.alpha() as u32,
+        );
+        Polarity::of(text, background) == polarity && lc(text, background).abs() >= target
+    };
+    let (mut failing, mut passing) = match polarity {
+        Polarity::DarkOnLight => (100.0, 0.0),
+        Polarity::LightOnDark => (0.0, 100.0),
+    };
+    if !reaches(passing) {
+        return None;
+    }
+    while (failing - passing).abs() > 0.01 {
+        let mid = (failing + passing) / 2.0;
+        if reaches(mid) {
+            passing = mid;
+        } else {
+            failing = mid;
+        }
+    }
+    Some(passing)
+}
@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::scheme::Scheme;
+
+use super::ratio_of_argb;
+
+const AA_TEXT: f64 = 4.5;
+const AA_LARGE_TEXT: f64 = 3.0;
+const AAA_TEXT: f64 = 7.0;
+const AAA_LARGE_TEXT: f64 = 4.5;
+const AA_NON_TEXT: f64 = 3.0;
+
+/**
+ * WCAG 2 conformance levels. With the `serde` feature, written as `"aa"` and
+ * `"aaa"`, in line with the camelCase names used everywhere else.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Level {
+    #[cfg_attr(feature = "serde", serde(rename = "aa"))]
+    AA,
+    #[cfg_attr(feature = "serde", serde(rename = "aaa"))]
+    AAA,
+}
+
+/**
+ * Whether the foreground of a check is text or a non-text element such as a
+ * border or icon, which WCAG holds to different thresholds.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum Usage {
+    Text,
+    NonText,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ContrastCheck {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub usage: Usage,
+    pub ratio: f64,
+    pub aa_text: bool,
+    pub aa_large_text: bool,
+    pub aaa_text: bool,
+    pub aaa_large_text: bool,
+    pub aa_non_text: bool,
+}
+
+impl ContrastCheck {
+    pub fn new(
+        foreground: (&'static str, u32),
+        background: (&'static str, u32),
+        usage: Usage,
+    ) -> Self {
+        let ratio = ratio_of_argb(foreground.1, background.1);
+        Self {
+            foreground: foreground.0,
+            background: background.0,
+            usage,
+            ratio,
+            aa_text: ratio >= AA_TEXT,
+            aa_large_text: ratio >= AA_LARGE_TEXT,
+            aaa_text: ratio >= AAA_TEXT,
+            aaa_large_text: ratio >= AAA_LARGE_TEXT,
+            aa_non_text: ratio >= AA_NON_TEXT,
+        }
+    }
+
+    /**
+     * Whether the pair meets `level` for its usage. WCAG only defines AA for
+     * non-text contrast, so non-text checks use the AA threshold for both.
+     */
+    pub fn passes(&self, level: Level) -> bool {
+        match (self.usage, level) {
+            (Usage::Text, Level::AA) => self.aa_text,
+            (Usage::Text, Level::AAA) => self.aaa_text,
+            (Usage::NonText, _) => self.aa_non_text,
+        }
+    }
+}
+
+/**
+ * Contrast of every content/container pair of a scheme, plus outline against
+ * surface.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct AuditReport {
+    pub checks: Vec<ContrastCheck>,
+    pub aa: bool,
+    pub aaa: bool,
+}
+
+impl AuditReport {
+    pub fn of(scheme: &Scheme) -> Self {
+        let mut checks: Vec<ContrastCheck> = scheme
+            .pairs()
+            .into_iter()
+            .map(|(container, content)| ContrastCheck::new(content, container, Usage::Text))
+            .collect();
+        checks.push(ContrastCheck::new(
+            ("outline", scheme.outline()),
+            ("surface", scheme.surface()),
+            Usage::NonText,
+        ));
+        Self {
+            aa: checks.iter().all(|check| check.passes(Level::AA)),
+            aaa: checks.iter().all(|check| check.passes(Level::AAA)),
+            checks,
+        }
+    }
+
+    pub fn passes(&self, level: Level) -> bool {
+        match level {
+            Level::AA => self.aa,
+            Level::AAA => self.aaa,
+        }
+    }
+
+    pub fn failures(&self, level: Level) -> impl Iterator<Item = &ContrastCheck> {
+        self.checks.iter().filter(move |check| !check.passes(level))
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn json(&self) -> Result<alloc::string::String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
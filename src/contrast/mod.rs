@@ -0,0 +1,81 @@
+use crate::utils::color::{lstar_from_y, xyz_from_argb, y_from_lstar};
+
+pub mod apca;
+pub mod audit;
+pub mod repair;
+
+/**
+ * The WCAG 2 contrast ratio of two tones, from 1 (identical lightness) to 21
+ * (black against white). WCAG asks for 4.5 for normal text and 3 for large
+ * text and non-text UI.
+ */
+pub fn ratio_of_tones(tone_a: f64, tone_b: f64) -> f64 {
+    let tone_a = tone_a.clamp(0.0, 100.0);
+    let tone_b = tone_b.clamp(0.0, 100.0);
+    ratio_of_ys(y_from_lstar(tone_a), y_from_lstar(tone_b))
+}
+
+pub fn ratio_of_argb(a: u32, b: u32) -> f64 {
+    ratio_of_ys(xyz_from_argb(a)[1], xyz_from_argb(b)[1])
+}
+
+pub fn ratio_of_ys(y1: f64, y2: f64) -> f64 {
+    let lighter = y1.max(y2);
+    let darker = y1.min(y2);
+    (lighter + 5.0) / (darker + 5.0)
+}
+
+/**
+ * A tone at least `ratio` lighter than `tone`, or `None` if the ratio cannot
+ * be reached.
+ */
+pub fn lighter(tone: f64, ratio: f64) -> Option<f64> {
+    if !(0.0..=100.0).contains(&tone) {
+        return None;
+    }
+    let dark_y = y_from_lstar(tone);
+    let light_y = ratio * (dark_y + 5.0) - 5.0;
+    let real_contrast = ratio_of_ys(light_y, dark_y);
+    let delta = (real_contrast - ratio).abs();
+    if real_contrast < ratio && delta > 0.04 {
+        return None;
+    }
+    // Add a small margin so the rounding of the solved color does not pull
+    // the ratio back under the requested one.
+    let value = lstar_from_y(light_y) + 0.4;
+    (0.0..=100.0).contains(&value).then_some(value)
+}
+
+/**
+ * A tone at least `ratio` darker than `tone`, or `None` if the ratio cannot
+ * be reached.
+ */
+pub fn darker(tone: f64, ratio: f64) -> Option<f64> {
+    if !(0.0..=100.0).contains(&tone) {
+        return None;
+    }
+    let light_y = y_from_lstar(tone);
+    let dark_y = (light_y + 5.0) / ratio - 5.0;
+    let real_contrast = ratio_of_ys(light_y, dark_y);
+    let delta = (real_contrast - ratio).abs();
+    if real_contrast < ratio && delta > 0.04 {
+        return None;
+    }
+    let value = lstar_from_y(dark_y) - 0.4;
+    (0.0..=100.0).contains(&value).then_some(value)
+}
+
+/**
+ * Like [`lighter`], but falls back to white when the ratio cannot be
+ * reached.
+ */
+pub fn lighter_unsafe(tone: f64, ratio: f64) -> f64 {
+    lighter(tone, ratio).unwrap_or(100.0)
+}
+
+/**
+ * Like [`darker`], but falls back to black when the ratio cannot be reached.
+ */
+pub fn darker_unsafe(tone: f64, ratio: f64) -> f64 {
+    darker(tone, ratio).unwrap_or(0.0)
+}
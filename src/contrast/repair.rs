@@ -0,0 +1,107 @@
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    hct::HCT,
+    scheme::Scheme,
+    utils::color::{alpha_from_argb, argb_with_alpha},
+};
+
+use super::{darker, lighter, ratio_of_argb};
+
+/**
+ * Step used to nudge a tone further when rounding to ARGB leaves the ratio
+ * just short of the target.
+ */
+const TONE_NUDGE: f64 = 0.5;
+
+/**
+ * A content role whose tone was changed to restore contrast against its
+ * container.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Repair {
+    pub role: &'static str,
+    pub against: &'static str,
+    pub before: u32,
+    pub after: u32,
+    pub ratio_before: f64,
+    pub ratio_after: f64,
+}
+
+/**
+ * Moves the tone of every content role that falls short of `ratio` against
+ * its container, keeping its hue and chroma. The tone moves away from the
+ * container on the side the content already is, switching sides only when
+ * that side cannot reach the ratio. Returns what was changed.
+ */
+pub fn repair(scheme: &mut Scheme, ratio: f64) -> Vec<Repair> {
+    let mut repairs = vec![];
+    for ((container_name, container), (content_name, content)) in scheme.pairs() {
+        let ratio_before = ratio_of_argb(content, container);
+        if ratio_before >= ratio {
+            continue;
+        }
+        let after = repaired(content, container, ratio);
+        scheme.set_role(content_name, after);
+        repairs.push(Repair {
+            role: content_name,
+            against: container_name,
+            before: content,
+            after,
+            ratio_before,
+            ratio_after: ratio_of_argb(after, container),
+        });
+    }
+    repairs
+}
+
+fn repaired(content: u32, container: u32, ratio: f64) -> u32 {
+    // Every step is solved from the original hue and chroma, so gamut
+    // clamping at one tone doesn't carry over to the next.
+    let original: HCT = content.into();
+    let (hue, chroma) = (original.hue(), original.chroma());
+    let solve = |tone: f64| {
+        argb_with_alpha(HCT::new(hue, chroma, tone).argb(), alpha_from_argb(content))
+    };
+    let container_tone = HCT::from(container).tone();
+    let prefer_lighter = original.tone() >= container_tone;
+    let (mut tone, lighten) = match (
+        prefer_lighter,
+        lighter(container_tone, ratio),
+        darker(container_tone, ratio),
+    ) {
+        (true, Some(tone), _) => (tone, true),
+        (false, _, Some(tone)) => (tone, false),
+        (true, None, Some(tone)) => (tone, false),
+        (false, Some(tone), None) => (tone, true),
+        (_, None, None) => {
+            // Neither side reaches the ratio, so go as far as possible.
+            let to_white = ratio_of_argb(0xffffffff, container);
+            let to_black = ratio_of_argb(0xff000000, container);
+            if to_white >= to_black {
+                (100.0, true)
+            } else {
+                (0.0, false)
+            }
+        }
+    };
+    let mut argb = solve(tone);
+    while ratio_of_argb(argb, container) < ratio {
+        let next = if lighten {
+            tone + TONE_NUDGE
+        } else {
+            tone - TONE_NUDGE
+        };
+        if !(0.0..=100.0).contains(&next) {
+            break;
+        }
+        tone = next;
+        argb = solve(tone);
+    }
+    argb
+}
@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    hct::cam16::Cam16,
+    scheme::Scheme,
+    utils::{
+        color::{
+            alpha_from_argb, argb_from_linrgb, argb_with_alpha, blue_from_argb, green_from_argb,
+            linearized, red_from_argb,
+        },
+        math::matrix_multiply,
+    },
+};
+
+const PROTANOPIA: [[f64; 3]; 3] = [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+];
+
+const DEUTERANOPIA: [[f64; 3]; 3] = [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+];
+
+const TRITANOPIA: [[f64; 3]; 3] = [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+];
+
+const ACHROMATOPSIA: [[f64; 3]; 3] = [
+    [0.2126, 0.7152, 0.0722],
+    [0.2126, 0.7152, 0.0722],
+    [0.2126, 0.7152, 0.0722],
+];
+
+/**
+ * Color vision deficiencies that can be simulated. The dichromacies use the
+ * full severity matrices of Machado, Oliveira and Fernandes (2009), and
+ * achromatopsia keeps only relative luminance.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum Deficiency {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+    Achromatopsia,
+}
+
+impl Deficiency {
+    pub const ALL: [Deficiency; 4] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+        Deficiency::Achromatopsia,
+    ];
+
+    fn matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => PROTANOPIA,
+            Deficiency::Deuteranopia => DEUTERANOPIA,
+            Deficiency::Tritanopia => TRITANOPIA,
+            Deficiency::Achromatopsia => ACHROMATOPSIA,
+        }
+    }
+}
+
+/**
+ * How `argb` appears with the given deficiency. Alpha is kept.
+ */
+pub fn simulate(argb: u32, deficiency: Deficiency) -> u32 {
+    let linrgb = [
+        linearized(red_from_argb(argb)),
+        linearized(green_from_argb(argb)),
+        linearized(blue_from_argb(argb)),
+    ];
+    let simulated = argb_from_linrgb(matrix_multiply(linrgb, deficiency.matrix()));
+    argb_with_alpha(simulated, alpha_from_argb(argb))
+}
+
+/**
+ * How the accent roles of a scheme hold up under one deficiency.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Distinguishability {
+    pub deficiency: Deficiency,
+    /// The two accent roles that end up closest together.
+    pub closest: (&'static str, &'static str),
+    /// Their [`Cam16::distance`] after simulation.
+    pub distance: f64,
+    pub distinguishable: bool,
+}
+
+/**
+ * Checks, for every deficiency, that primary, secondary, tertiary and error
+ * stay at least `min_distance` apart in CAM16-UCS once simulated.
+ */
+pub fn check_accents(scheme: &Scheme, min_distance: f64) -> Vec<Distinguishability> {
+    let accents = [
+        ("primary", scheme.primary()),
+        ("secondary", scheme.secondary()),
+        ("tertiary", scheme.tertiary()),
+        ("error", scheme.error()),
+    ];
+    Deficiency::ALL
+        .into_iter()
+        .map(|deficiency| {
+            let simulated: Vec<(&'static str, Cam16)> = accents
+                .iter()
+                .map(|(name, argb)| (*name, simulate(*argb, deficiency).into()))
+                .collect();
+            let mut closest = (accents[0].0, accents[1].0);
+            let mut distance = f64::INFINITY;
+            for (i, (a_name, a)) in simulated.iter().enumerate() {
+                for (b_name, b) in &simulated[i + 1..] {
+                    let d = a.distance(b);
+                    if d < distance {
+                        distance = d;
+                        closest = (a_name, b_name);
+                    }
+                }
+            }
+            Distinguishability {
+                deficiency,
+                closest,
+                distance,
+                distinguishable: distance >= min_distance,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,172 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    hct::{cam16::Cam16, HCT},
+    palette::{CorePalette, TonalPalette},
+    utils::math::{lerp, sanitize_degrees_double},
+};
+
+/**
+ * Spacing of the hues considered when picking categorical colors.
+ */
+const CANDIDATE_HUE_STEP: f64 = 5.0;
+
+/**
+ * Number of tones sampled across the tone band for categorical candidates.
+ */
+const CANDIDATE_TONES: usize = 3;
+
+/**
+ * Categorical chart colors: as distinct from each other as possible in
+ * CAM16-UCS, while staying within a tone band and above a minimum chroma so
+ * every series reads equally strong. The first color is the seed itself,
+ * moved into the band.
+ */
+pub struct Categorical {
+    seed: u32,
+    min_tone: f64,
+    max_tone: f64,
+    min_chroma: f64,
+}
+
+impl Categorical {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            seed,
+            min_tone: 50.0,
+            max_tone: 70.0,
+            min_chroma: 36.0,
+        }
+    }
+
+    pub fn with_tone_range(mut self, min_tone: f64, max_tone: f64) -> Self {
+        self.min_tone = min_tone.min(max_tone);
+        self.max_tone = max_tone.max(min_tone);
+        self
+    }
+
+    pub fn with_min_chroma(mut self, min_chroma: f64) -> Self {
+        self.min_chroma = min_chroma;
+        self
+    }
+
+    pub fn generate(&self, count: usize) -> Vec<u32> {
+        if count == 0 {
+            return vec![];
+        }
+        let seed: HCT = self.seed.into();
+        let chroma = seed.chroma().max(self.min_chroma);
+        let first = HCT::new(
+            seed.hue(),
+            chroma,
+            seed.tone().clamp(self.min_tone, self.max_tone),
+        );
+
+        let mut candidates: Vec<(u32, Cam16)> = self
+            .candidate_tones()
+            .flat_map(|tone| {
+                (0..(360.0 / CANDIDATE_HUE_STEP) as usize).map(move |i| {
+                    let hue = sanitize_degrees_double(seed.hue() + i as f64 * CANDIDATE_HUE_STEP);
+                    HCT::new(hue, chroma, tone)
+                })
+            })
+            .filter(|hct| self.reaches_min_chroma(hct))
+            .map(|hct| {
+                let argb = hct.argb();
+                (argb, Cam16::from(argb))
+            })
+            .collect();
+
+        // When the seed's hue can't reach the minimum chroma within the band,
+        // start from the candidate closest to it instead.
+        let first = (first.argb(), Cam16::from(first.argb()));
+        let mut chosen = vec![];
+        if self.reaches_min_chroma(&HCT::from(first.0)) {
+            chosen.push(first);
+        } else if let Some((index, _)) = candidates
+            .iter()
+            .enumerate()
+            .map(|(i, (_, cam))| (i, cam.distance(&first.1)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+        {
+            chosen.push(candidates.swap_remove(index));
+        }
+        while chosen.len() < count && !candidates.is_empty() {
+            let (index, _) = candidates
+                .iter()
+                .enumerate()
+                .map(|(i, (_, cam))| {
+                    let nearest = chosen
+                        .iter()
+                        .map(|(_, other)| cam.distance(other))
+                        .fold(f64::INFINITY, f64::min);
+                    (i, nearest)
+                })
+                .fold((0, f64::NEG_INFINITY), |best, next| {
+                    if next.1 > best.1 {
+                        next
+                    } else {
+                        best
+                    }
+                });
+            chosen.push(candidates.swap_remove(index));
+        }
+        chosen.into_iter().map(|(argb, _)| argb).collect()
+    }
+
+    fn reaches_min_chroma(&self, hct: &HCT) -> bool {
+        hct.chroma() >= self.min_chroma - 0.5
+    }
+
+    fn candidate_tones(&self) -> impl Iterator<Item = f64> {
+        let (min, max) = (self.min_tone, self.max_tone);
+        (0..CANDIDATE_TONES).map(move |i| lerp(min, max, i as f64 / (CANDIDATE_TONES - 1) as f64))
+    }
+}
+
+/**
+ * A light to dark ramp of `count` colors from the seed's primary palette,
+ * for ordered data.
+ */
+pub fn sequential(seed: u32, count: usize) -> Vec<u32> {
+    let primary = CorePalette::of(seed).a1;
+    tone_ramp(count, 90.0, 25.0)
+        .map(|tone| primary.tone(tone))
+        .collect()
+}
+
+/**
+ * A ramp of `count` colors diverging from a light neutral midpoint towards
+ * the seed's primary hue on one end and its complement on the other, for
+ * data centered on a meaningful value.
+ */
+pub fn diverging(seed: u32, count: usize) -> Vec<u32> {
+    let core = CorePalette::of(seed);
+    let hct: HCT = seed.into();
+    let chroma = hct.chroma().max(48.0);
+    let low = core.a1;
+    let high =
+        TonalPalette::from_hue_and_chroma(sanitize_degrees_double(hct.hue() + 180.0), chroma);
+    let neutral = core.n1;
+
+    let half = count / 2;
+    let mut colors: Vec<u32> = tone_ramp(half + 1, 30.0, 90.0)
+        .take(half)
+        .map(|tone| low.tone(tone))
+        .collect();
+    if count % 2 == 1 {
+        colors.push(neutral.tone(95.0));
+    }
+    let mut upper: Vec<u32> = tone_ramp(half + 1, 30.0, 90.0)
+        .take(half)
+        .map(|tone| high.tone(tone))
+        .collect();
+    upper.reverse();
+    colors.extend(upper);
+    colors
+}
+
+fn tone_ramp(count: usize, from: f64, to: f64) -> impl Iterator<Item = f64> {
+    let steps = count.saturating_sub(1).max(1) as f64;
+    (0..count).map(move |i| lerp(from, to, i as f64 / steps))
+}
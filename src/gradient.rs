@@ -0,0 +1,203 @@
+use alloc::{format, string::String, vec, vec::Vec};
+
+use crate::{
+    hct::{
+        cam16::{Cam16, UCS},
+        HCT,
+    },
+    space::oklab::OkLab,
+    utils::{
+        color::{alpha_from_argb, argb_with_alpha},
+        math::{difference_degrees, lerp, rotation_direction, sanitize_degrees_double},
+        string::{hex_from_argb, hex_with_alpha_from_argb},
+    },
+};
+import_float!();
+
+/**
+ * Below this chroma a key color's hue is considered meaningless, and the hue
+ * of its neighbour is used instead.
+ */
+const ACHROMATIC_CHROMA: f64 = 2.0;
+
+/**
+ * The space gradient stops are interpolated in.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GradientSpace {
+    /// Hue along the shorter arc, chroma and tone linearly.
+    Hct,
+    /// Straight lines in CAM16-UCS, the space [`Cam16::distance`] measures.
+    Cam16Ucs,
+    OkLab,
+}
+
+/**
+ * Which coordinate, if any, is held constant across the gradient.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GradientMode {
+    Interpolate,
+    /// Every stop takes the tone of the first key color.
+    PreserveTone,
+    /// Every stop takes the chroma of the first key color, as far as the
+    /// sRGB gamut allows.
+    PreserveChroma,
+}
+
+/**
+ * A gradient through evenly spaced key colors.
+ */
+pub struct Gradient {
+    keys: Vec<u32>,
+    space: GradientSpace,
+    mode: GradientMode,
+}
+
+impl Gradient {
+    pub fn new(keys: &[u32]) -> Self {
+        Self {
+            keys: keys.to_vec(),
+            space: GradientSpace::Hct,
+            mode: GradientMode::Interpolate,
+        }
+    }
+
+    pub fn with_space(mut self, space: GradientSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: GradientMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /**
+     * The color at `t`, where 0 is the first key color and 1 the last. Alpha
+     * is interpolated linearly between the key colors.
+     */
+    pub fn at(&self, t: f64) -> u32 {
+        match self.keys.first() {
+            None => 0,
+            Some(first) => self.color_at(t, HCT::from(*first)),
+        }
+    }
+
+    /**
+     * `count` evenly spaced stops, including both ends.
+     */
+    pub fn stops(&self, count: usize) -> Vec<u32> {
+        let Some(first) = self.keys.first().map(|argb| HCT::from(*argb)) else {
+            return vec![0; count];
+        };
+        match count {
+            0 => vec![],
+            1 => vec![self.color_at(0.0, first)],
+            _ => (0..count)
+                .map(|i| self.color_at(i as f64 / (count - 1) as f64, first))
+                .collect(),
+        }
+    }
+
+    /**
+     * A CSS `linear-gradient()` with `count` stops, pointing at
+     * `angle_degrees`. Translucent stops are written as `#rrggbbaa`.
+     */
+    pub fn css(&self, count: usize, angle_degrees: f64) -> String {
+        let stops = self.stops(count);
+        let last = stops.len().saturating_sub(1).max(1) as f64;
+        let stops: Vec<String> = stops
+            .iter()
+            .enumerate()
+            .map(|(i, argb)| {
+                let percent = (i as f64 / last * 10000.0).round() / 100.0;
+                let hex = if alpha_from_argb(*argb) == 0xff {
+                    hex_from_argb(*argb)
+                } else {
+                    hex_with_alpha_from_argb(*argb)
+                };
+                format!("{hex} {percent}%")
+            })
+            .collect();
+        format!("linear-gradient({}deg, {})", angle_degrees, stops.join(", "))
+    }
+
+    /**
+     * [`Gradient::at`], with the first key color already converted, since
+     * the preserving modes need it for every stop.
+     */
+    fn color_at(&self, t: f64, first: HCT) -> u32 {
+        let len = self.keys.len();
+        if len == 1 {
+            let key = self.keys[0];
+            return argb_with_alpha(self.constrain(key, first), alpha_from_argb(key));
+        }
+        let position = t.clamp(0.0, 1.0) * (len - 1) as f64;
+        let segment = (position.floor() as usize).min(len - 2);
+        let amount = position - segment as f64;
+        let from = self.keys[segment];
+        let to = self.keys[segment + 1];
+        let alpha = lerp(alpha_from_argb(from) as f64, alpha_from_argb(to) as f64, amount);
+        let argb = match self.space {
+            GradientSpace::Hct => interpolate_hct(from, to, amount),
+            GradientSpace::Cam16Ucs => interpolate_cam16_ucs(from, to, amount),
+            GradientSpace::OkLab => interpolate_oklab(from, to, amount),
+        };
+        argb_with_alpha(self.constrain(argb, first), alpha.round() as u32)
+    }
+
+    fn constrain(&self, argb: u32, first: HCT) -> u32 {
+        let hct: HCT = argb.into();
+        match self.mode {
+            GradientMode::Interpolate => argb,
+            GradientMode::PreserveTone => HCT::new(hct.hue(), hct.chroma(), first.tone()).argb(),
+            GradientMode::PreserveChroma => HCT::new(hct.hue(), first.chroma(), hct.tone()).argb(),
+        }
+    }
+}
+
+fn interpolate_hct(from: u32, to: u32, amount: f64) -> u32 {
+    let from: HCT = from.into();
+    let to: HCT = to.into();
+    let (from_hue, to_hue) = match (
+        from.chroma() < ACHROMATIC_CHROMA,
+        to.chroma() < ACHROMATIC_CHROMA,
+    ) {
+        (true, false) => (to.hue(), to.hue()),
+        (false, true) => (from.hue(), from.hue()),
+        _ => (from.hue(), to.hue()),
+    };
+    let hue = sanitize_degrees_double(
+        from_hue
+            + difference_degrees(from_hue, to_hue) * amount * rotation_direction(from_hue, to_hue),
+    );
+    HCT::new(
+        hue,
+        lerp(from.chroma(), to.chroma(), amount),
+        lerp(from.tone(), to.tone(), amount),
+    )
+    .argb()
+}
+
+fn interpolate_cam16_ucs(from: u32, to: u32, amount: f64) -> u32 {
+    let from: Cam16 = from.into();
+    let to: Cam16 = to.into();
+    let ucs = UCS::new(
+        lerp(from.jstar, to.jstar, amount),
+        lerp(from.astar, to.astar, amount),
+        lerp(from.bstar, to.bstar, amount),
+    );
+    Cam16::from(ucs).into()
+}
+
+fn interpolate_oklab(from: u32, to: u32, amount: f64) -> u32 {
+    let from: OkLab = from.into();
+    let to: OkLab = to.into();
+    OkLab::new(
+        lerp(from.l, to.l, amount),
+        lerp(from.a, to.a, amount),
+        lerp(from.b, to.b, amount),
+    )
+    .into()
+}
@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+
+import_float!();
+use crate::utils::{
+    color::{blue_from_argb, green_from_argb, lstar_from_y, red_from_argb},
+    lut::LINEARIZED,
+};
+
+use super::{vc::ViewingConditions, HCT};
+
+/**
+ * Pixels converted together. Each stage of the conversion runs over a whole
+ * block, one array per channel, before the next stage starts. The linear
+ * stages are fixed-length loops over plain arrays, which the compiler can
+ * vectorize; the power, root and trigonometric stages still run per pixel.
+ */
+const LANES: usize = 8;
+
+/**
+ * Pixels handed to each rayon task, large enough to amortize scheduling.
+ */
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK: usize = 4096;
+
+/**
+ * Converts every pixel of `argbs` to HCT, with the same result as converting
+ * each with [`HCT::from`]. Faster than that, because channels are linearized
+ * once through a table, the viewing conditions and the terms depending only
+ * on them are computed once per slice, and only the CAM16 outputs HCT keeps
+ * are computed. With the `rayon` feature, large slices are converted in
+ * parallel.
+ */
+pub fn hct_from_argb_slice(argbs: &[u32]) -> Vec<HCT> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        argbs
+            .par_chunks(PARALLEL_CHUNK)
+            .flat_map_iter(|chunk| {
+                let mut hcts = Vec::with_capacity(chunk.len());
+                convert(chunk, &mut hcts);
+                hcts
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        let mut hcts = Vec::with_capacity(argbs.len());
+        convert(argbs, &mut hcts);
+        hcts
+    }
+}
+
+/**
+ * The ARGB of every color of `hcts`, including alpha.
+ */
+pub fn argb_from_hct_slice(hcts: &[HCT]) -> Vec<u32> {
+    hcts.iter().map(HCT::argb).collect()
+}
+
+/**
+ * The hue and chroma half of `Cam16::from_linrgb_in_viewing_conditions`, a
+ * block at a time. The arithmetic is the same, in the same order, so results
+ * match [`HCT::from`] exactly.
+ */
+fn convert(argbs: &[u32], hcts: &mut Vec<HCT>) {
+    let vc = &ViewingConditions::DEFAULT;
+    let alpha_factor = (1.64 - 0.29f64.powf(vc.n)).powf(0.73);
+    let j_exponent = vc.c * vc.z;
+
+    for block in argbs.chunks(LANES) {
+        let mut red = [0.0; LANES];
+        let mut green = [0.0; LANES];
+        let mut blue = [0.0; LANES];
+        for (i, &argb) in block.iter().enumerate() {
+            red[i] = LINEARIZED[red_from_argb(argb) as usize];
+            green[i] = LINEARIZED[green_from_argb(argb) as usize];
+            blue[i] = LINEARIZED[blue_from_argb(argb) as usize];
+        }
+
+        let mut x = [0.0; LANES];
+        let mut y = [0.0; LANES];
+        let mut z = [0.0; LANES];
+        for i in 0..LANES {
+            x[i] = 0.41233895 * red[i] + 0.35762064 * green[i] + 0.18051042 * blue[i];
+            y[i] = 0.2126 * red[i] + 0.7152 * green[i] + 0.0722 * blue[i];
+            z[i] = 0.01932141 * red[i] + 0.11916382 * green[i] + 0.95034478 * blue[i];
+        }
+
+        let mut r_d = [0.0; LANES];
+        let mut g_d = [0.0; LANES];
+        let mut b_d = [0.0; LANES];
+        for i in 0..LANES {
+            r_d[i] = vc.rgb_d[0] * (0.401288 * x[i] + 0.650173 * y[i] - 0.051461 * z[i]);
+            g_d[i] = vc.rgb_d[1] * (-0.250268 * x[i] + 1.204414 * y[i] + 0.045854 * z[i]);
+            b_d[i] = vc.rgb_d[2] * (-0.002079 * x[i] + 0.048952 * y[i] + 0.953127 * z[i]);
+        }
+
+        let mut r_a = [0.0; LANES];
+        let mut g_a = [0.0; LANES];
+        let mut b_a = [0.0; LANES];
+        for i in 0..block.len() {
+            r_a[i] = adapted(r_d[i], vc.fl);
+            g_a[i] = adapted(g_d[i], vc.fl);
+            b_a[i] = adapted(b_d[i], vc.fl);
+        }
+
+        let mut a = [0.0; LANES];
+        let mut b = [0.0; LANES];
+        let mut u = [0.0; LANES];
+        let mut p2 = [0.0; LANES];
+        for i in 0..LANES {
+            a[i] = (11.0 * r_a[i] + -12.0 * g_a[i] + b_a[i]) / 11.0;
+            b[i] = (r_a[i] + g_a[i] - 2.0 * b_a[i]) / 9.0;
+            u[i] = (20.0 * r_a[i] + 20.0 * g_a[i] + 21.0 * b_a[i]) / 20.0;
+            p2[i] = (40.0 * r_a[i] + 20.0 * g_a[i] + b_a[i]) / 20.0;
+        }
+
+        for (i, &argb) in block.iter().enumerate() {
+            let atan_degrees = (b[i].atan2(a[i]) * 180.0) / core::f64::consts::PI;
+            let hue = if atan_degrees < 0.0 {
+                atan_degrees + 360.0
+            } else if atan_degrees >= 360.0 {
+                atan_degrees - 360.0
+            } else {
+                atan_degrees
+            };
+            let j = 100.0 * ((p2[i] * vc.nbb) / vc.aw).powf(j_exponent);
+            let hue_prime = if hue < 20.14 { hue + 360.0 } else { hue };
+            let e_hue = 0.25 * (((hue_prime * core::f64::consts::PI) / 180.0 + 2.0).cos() + 3.8);
+            let p1 = (50000.0 / 13.0) * e_hue * vc.nc * vc.ncb;
+            let t = (p1 * (a[i] * a[i] + b[i] * b[i]).sqrt()) / (u[i] + 0.305);
+            let alpha = t.powf(0.9) * alpha_factor;
+            hcts.push(HCT {
+                hue,
+                chroma: alpha * (j / 100.0).sqrt(),
+                tone: lstar_from_y(y[i]),
+                argb,
+                rgb: argb.into(),
+            });
+        }
+    }
+}
+
+/**
+ * A cone response after CAM16's nonlinear adaptation.
+ */
+fn adapted(component: f64, fl: f64) -> f64 {
+    let af = ((fl * component.abs()) / 100.0).powf(0.42);
+    (component.signum() * 400.0 * af) / (af + 27.13)
+}
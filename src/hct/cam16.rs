@@ -1,11 +1,18 @@
 use crate::{
-    hct::vc,
-    utils::color::{argb_from_xyz, linearized},
+    space::{rgb::Rgb, xyz::LinearRgb},
+    utils::color::{
+        argb_from_xyz, blue_from_argb, green_from_argb, linearized, linrgb_from_xyz,
+        red_from_argb,
+    },
 };
 
 use super::vc::ViewingConditions;
+import_float!();
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JCh {
     j: f64,
     c: f64,
@@ -24,7 +31,9 @@ impl From<(f64, f64, f64)> for JCh {
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UCS {
     jstar: f64,
     astar: f64,
@@ -65,6 +74,7 @@ impl From<(f64, f64, f64)> for UCS {
  * hue 203, chroma 3, lightness 100)
  */
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cam16 {
     pub hue: f64,
     pub chroma: f64,
@@ -84,14 +94,32 @@ impl Cam16 {
      */
     pub fn from_int_in_viewing_conditions(
         argb: u32,
-        viewing_conditions: ViewingConditions,
+        viewing_conditions: &ViewingConditions,
     ) -> Self {
-        let red = (argb & 0x00ff0000) >> 16;
-        let green = (argb & 0x0000ff00) >> 8;
-        let blue = argb & 0x000000ff;
-        let red_l = linearized(red);
-        let green_l = linearized(green);
-        let blue_l = linearized(blue);
+        let red = red_from_argb(argb);
+        let green = green_from_argb(argb);
+        let blue = blue_from_argb(argb);
+        let linrgb = [linearized(red), linearized(green), linearized(blue)];
+        Self::from_linrgb_in_viewing_conditions(linrgb, viewing_conditions)
+    }
+
+    /**
+     * Like [`Cam16::from_int_in_viewing_conditions`], but takes unquantized
+     * sRGB so no precision is lost to 8-bit channels.
+     */
+    pub fn from_rgb_in_viewing_conditions(
+        rgb: Rgb<f64>,
+        viewing_conditions: &ViewingConditions,
+    ) -> Self {
+        let LinearRgb { r, g, b } = rgb.into();
+        Self::from_linrgb_in_viewing_conditions([r, g, b], viewing_conditions)
+    }
+
+    fn from_linrgb_in_viewing_conditions(
+        linrgb: [f64; 3],
+        viewing_conditions: &ViewingConditions,
+    ) -> Self {
+        let [red_l, green_l, blue_l] = linrgb;
         let x = 0.41233895 * red_l + 0.35762064 * green_l + 0.18051042 * blue_l;
         let y = 0.2126 * red_l + 0.7152 * green_l + 0.0722 * blue_l;
         let z = 0.01932141 * red_l + 0.11916382 * green_l + 0.95034478 * blue_l;
@@ -106,7 +134,7 @@ impl Cam16 {
 
         let r_af = ((viewing_conditions.fl * r_d.abs()) / 100.0).powf(0.42);
         let g_af = ((viewing_conditions.fl * g_d.abs()) / 100.0).powf(0.42);
-        let b_af = (viewing_conditions.fl) / (g_af + 27.13);
+        let b_af = ((viewing_conditions.fl * b_d.abs()) / 100.0).powf(0.42);
 
         let r_a = (r_d.signum() * 400.0 * r_af) / (r_af + 27.13);
         let g_a = (g_d.signum() * 400.0 * g_af) / (g_af + 27.13);
@@ -117,7 +145,7 @@ impl Cam16 {
         let u = (20.0 * r_a + 20.0 * g_a + 21.0 * b_a) / 20.0;
         let p2 = (40.0 * r_a + 20.0 * g_a + b_a) / 20.0;
         let atan2 = b.atan2(a);
-        let atan_degrees = (atan2 * 180.0) / std::f64::consts::PI;
+        let atan_degrees = (atan2 * 180.0) / core::f64::consts::PI;
         let hue = if atan_degrees < 0.0 {
             atan_degrees + 360.0
         } else if atan_degrees >= 360.0 {
@@ -125,7 +153,7 @@ impl Cam16 {
         } else {
             atan_degrees
         };
-        let hue_radians = (hue * std::f64::consts::PI) / 180.0;
+        let hue_radians = (hue * core::f64::consts::PI) / 180.0;
 
         let ac = p2 * viewing_conditions.nbb;
         let j =
@@ -135,7 +163,7 @@ impl Cam16 {
             * (viewing_conditions.aw + 4.0)
             * viewing_conditions.f_l_root;
         let hue_prime = if hue < 20.14 { hue + 360.0 } else { hue };
-        let e_hue = 0.25 * (((hue_prime * std::f64::consts::PI) / 180.0 + 2.0).cos() + 3.8);
+        let e_hue = 0.25 * (((hue_prime * core::f64::consts::PI) / 180.0 + 2.0).cos() + 3.8);
         let p1 = (50000.0 / 13.0) * e_hue * viewing_conditions.nc * viewing_conditions.ncb;
         let t = (p1 * (a * a + b * b).sqrt()) / (u + 0.305);
         let alpha = t.powf(0.9) * (1.64 - 0.29f64.powf(viewing_conditions.n)).powf(0.73);
@@ -144,7 +172,7 @@ impl Cam16 {
         let s = 50.0 * ((alpha * viewing_conditions.c) / (viewing_conditions.aw + 4.0)).sqrt();
 
         let jstar = ((1.0 + 100.0 * 0.007) * j) / (1.0 + 0.007 * j);
-        let mstar = (1.0 / 0.0228) * (1.0 + 0.0228 * m).log(std::f64::consts::E);
+        let mstar = (1.0 / 0.0228) * (1.0 + 0.0228 * m).log(core::f64::consts::E);
         let astar = mstar * hue_radians.cos();
         let bstar = mstar * hue_radians.sin();
 
@@ -161,7 +189,7 @@ impl Cam16 {
         }
     }
 
-    pub fn from_jch_in_viewing_conditions(jch: JCh, viewing_conditions: ViewingConditions) -> Self {
+    pub fn from_jch_in_viewing_conditions(jch: JCh, viewing_conditions: &ViewingConditions) -> Self {
         let JCh { j, c, h } = jch;
         let q = (4.0 / viewing_conditions.c)
             * (j / 100.0).sqrt()
@@ -170,9 +198,9 @@ impl Cam16 {
         let m = c * viewing_conditions.f_l_root;
         let alpha = c / (j / 100.0).sqrt();
         let s = 50.0 * ((alpha * viewing_conditions.c) / (viewing_conditions.aw + 4.0)).sqrt();
-        let hue_radians = (h * std::f64::consts::PI) / 180.0;
+        let hue_radians = (h * core::f64::consts::PI) / 180.0;
         let jstar = ((1.0 + 100.0 * 0.007) * j) / (1.0 + 0.007 * j);
-        let mstar = (1.0 / 0.0228) * (1.0 + 0.0228 * m).log(std::f64::consts::E);
+        let mstar = (1.0 / 0.0228) * (1.0 + 0.0228 * m).log(core::f64::consts::E);
         let astar = mstar * (hue_radians).cos();
         let bstar = mstar * (hue_radians).sin();
         Cam16 {
@@ -188,7 +216,7 @@ impl Cam16 {
         }
     }
 
-    pub fn from_ucs_in_viewing_conditions(ucs: UCS, viewing_conditions: ViewingConditions) -> Self {
+    pub fn from_ucs_in_viewing_conditions(ucs: UCS, viewing_conditions: &ViewingConditions) -> Self {
         let UCS {
             jstar,
             astar,
@@ -197,10 +225,10 @@ impl Cam16 {
         let a = astar;
         let b = bstar;
         let m = (a * a + b * b).sqrt();
-        let M = ((m * 0.0228).exp() - 1.0) / 0.0228;
-        let c = M / viewing_conditions.f_l_root;
+        let big_m = ((m * 0.0228).exp() - 1.0) / 0.0228;
+        let c = big_m / viewing_conditions.f_l_root;
         let h = {
-            let h = b.atan2(a) * (180.0 / std::f64::consts::PI);
+            let h = b.atan2(a) * (180.0 / core::f64::consts::PI);
             if h < 0.0 {
                 h + 360.0
             } else {
@@ -219,7 +247,22 @@ impl Cam16 {
         1.41 * d_eprime.powf(0.63)
     }
 
-    pub fn viewed(&self, viewing_conditions: ViewingConditions) -> u32 {
+    pub fn viewed(&self, viewing_conditions: &ViewingConditions) -> u32 {
+        let [x, y, z] = self.viewed_xyz(viewing_conditions);
+        argb_from_xyz(x, y, z)
+    }
+
+    /**
+     * Like [`Cam16::viewed`], but returns unquantized sRGB. Channels fall
+     * outside 0..1 when the color is outside the sRGB gamut.
+     */
+    pub fn viewed_rgb(&self, viewing_conditions: &ViewingConditions) -> Rgb<f64> {
+        let [x, y, z] = self.viewed_xyz(viewing_conditions);
+        let [r, g, b] = linrgb_from_xyz(x, y, z);
+        LinearRgb::new(r, g, b).into()
+    }
+
+    fn viewed_xyz(&self, viewing_conditions: &ViewingConditions) -> [f64; 3] {
         let alpha = {
             if self.chroma == 0.0 || self.j == 0.0 {
                 0.0
@@ -228,7 +271,7 @@ impl Cam16 {
             }
         };
         let t = (alpha / (1.64 - 0.29f64.powf(viewing_conditions.n)).powf(0.73)).powf(1.0 / 0.9);
-        let h_rad = (self.hue * std::f64::consts::PI) / 180.0;
+        let h_rad = (self.hue * core::f64::consts::PI) / 180.0;
 
         let e_hue = 0.25 * ((h_rad + 2.0).cos() + 3.8);
         let ac = viewing_conditions.aw
@@ -263,30 +306,42 @@ impl Cam16 {
         let y = 0.38752654 * r_f + 0.62144744 * g_f - 0.00897398 * b_f;
         let z = -0.01584150 * r_f - 0.03412294 * g_f + 1.04996444 * b_f;
 
-        argb_from_xyz(x, y, z)
+        [x, y, z]
     }
 }
 
 impl From<u32> for Cam16 {
     fn from(argb: u32) -> Self {
-        Self::from_int_in_viewing_conditions(argb, ViewingConditions::default())
+        Self::from_int_in_viewing_conditions(argb, &ViewingConditions::DEFAULT)
+    }
+}
+
+impl From<Rgb<f64>> for Cam16 {
+    fn from(rgb: Rgb<f64>) -> Self {
+        Self::from_rgb_in_viewing_conditions(rgb, &ViewingConditions::DEFAULT)
     }
 }
 
 impl From<JCh> for Cam16 {
     fn from(jch: JCh) -> Self {
-        Self::from_jch_in_viewing_conditions(jch, ViewingConditions::default())
+        Self::from_jch_in_viewing_conditions(jch, &ViewingConditions::DEFAULT)
     }
 }
 
 impl From<UCS> for Cam16 {
     fn from(ucs: UCS) -> Self {
-        Self::from_ucs_in_viewing_conditions(ucs, ViewingConditions::default())
+        Self::from_ucs_in_viewing_conditions(ucs, &ViewingConditions::DEFAULT)
+    }
+}
+
+impl From<Cam16> for u32 {
+    fn from(cam: Cam16) -> Self {
+        cam.viewed(&ViewingConditions::DEFAULT)
     }
 }
 
-impl Into<u32> for Cam16 {
-    fn into(self) -> u32 {
-        self.viewed(ViewingConditions::default())
+impl From<Cam16> for Rgb<f64> {
+    fn from(cam: Cam16) -> Self {
+        cam.viewed_rgb(&ViewingConditions::DEFAULT)
     }
 }
@@ -0,0 +1,202 @@
+use core::fmt;
+
+use crate::{
+    space::xyz::Xyz,
+    utils::{
+        color::{linrgb_from_xyz, xyz_from_linrgb},
+        math::matrix_multiply,
+    },
+};
+import_float!();
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const DISPLAY_P3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.48657094864821604, 0.265667693169093, 0.1982172852343625],
+    [0.22897456406974867, 0.6917385218365062, 0.079286914093745],
+    [0.0, 0.045113381858902624, 1.043944368900976],
+];
+
+const XYZ_TO_DISPLAY_P3: [[f64; 3]; 3] = [
+    [2.493496911941426, -0.931383617919124, -0.4027107844507169],
+    [-0.8294889695615746, 1.7626640603183465, 0.02362468584194355],
+    [0.035845830243784446, -0.07617238926804179, 0.9568845240076871],
+];
+
+const REC2020_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6369580483012911, 0.14461690358620832, 0.16888097516417208],
+    [0.262700212011267, 0.6779980715188708, 0.05930171646986195],
+    [0.0, 0.028072693049087428, 1.0609850577107909],
+];
+
+const XYZ_TO_REC2020: [[f64; 3]; 3] = [
+    [1.7166511879712683, -0.3556707837763925, -0.2533662813736599],
+    [-0.6666843518324893, 1.6164812366349395, 0.015768545813911142],
+    [0.01763985744531079, -0.04277061325780853, 0.9421031212354739],
+];
+
+const REC2020_ALPHA: f64 = 1.09929682680944;
+const REC2020_BETA: f64 = 0.018053968510807;
+
+/**
+ * RGB color spaces the HCT solver can map into. All of them share the D65
+ * white point, so only the primaries and transfer functions differ.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum Gamut {
+    Srgb,
+    DisplayP3,
+    Rec2020,
+}
+
+impl Gamut {
+    /**
+     * The identifier used by the CSS `color()` function.
+     */
+    pub fn css_name(&self) -> &'static str {
+        match self {
+            Gamut::Srgb => "srgb",
+            Gamut::DisplayP3 => "display-p3",
+            Gamut::Rec2020 => "rec2020",
+        }
+    }
+
+    /**
+     * Removes the transfer function from an encoded component in 0..1,
+     * returning a linear component in 0..100. Negative components are
+     * mirrored, as in CSS Color 4.
+     */
+    pub fn linearized(&self, component: f64) -> f64 {
+        let sign = component.signum();
+        let component = component.abs();
+        let linear = match self {
+            Gamut::Srgb | Gamut::DisplayP3 => {
+                if component <= 0.040449936 {
+                    component / 12.92
+                } else {
+                    ((component + 0.055) / 1.055).powf(2.4)
+                }
+            }
+            Gamut::Rec2020 => {
+                if component < REC2020_BETA * 4.5 {
+                    component / 4.5
+                } else {
+                    ((component + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+                }
+            }
+        };
+        sign * linear * 100.0
+    }
+
+    /**
+     * Applies the transfer function to a linear component in 0..100,
+     * returning an encoded component in 0..1. Negative components are
+     * mirrored, as in CSS Color 4.
+     */
+    pub fn delinearized(&self, component: f64) -> f64 {
+        let normalized = component.abs() / 100.0;
+        let encoded = match self {
+            Gamut::Srgb | Gamut::DisplayP3 => {
+                if normalized <= 0.0031308 {
+                    normalized * 12.92
+                } else {
+                    1.055 * normalized.powf(1.0 / 2.4) - 0.055
+                }
+            }
+            Gamut::Rec2020 => {
+                if normalized < REC2020_BETA {
+                    normalized * 4.5
+                } else {
+                    REC2020_ALPHA * normalized.powf(0.45) - (REC2020_ALPHA - 1.0)
+                }
+            }
+        };
+        component.signum() * encoded
+    }
+
+    fn xyz_from_linrgb(&self, linrgb: [f64; 3]) -> [f64; 3] {
+        match self {
+            Gamut::Srgb => xyz_from_linrgb(linrgb),
+            Gamut::DisplayP3 => matrix_multiply(linrgb, DISPLAY_P3_TO_XYZ),
+            Gamut::Rec2020 => matrix_multiply(linrgb, REC2020_TO_XYZ),
+        }
+    }
+
+    fn linrgb_from_xyz(&self, xyz: [f64; 3]) -> [f64; 3] {
+        match self {
+            Gamut::Srgb => linrgb_from_xyz(xyz[0], xyz[1], xyz[2]),
+            Gamut::DisplayP3 => matrix_multiply(xyz, XYZ_TO_DISPLAY_P3),
+            Gamut::Rec2020 => matrix_multiply(xyz, XYZ_TO_REC2020),
+        }
+    }
+}
+
+/**
+ * An encoded, unquantized RGB color in a particular [`Gamut`]. Channels are
+ * in 0..1. Formats as a CSS `color()` function.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GamutRgb {
+    pub gamut: Gamut,
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl GamutRgb {
+    pub fn new(gamut: Gamut, r: f64, g: f64, b: f64) -> Self {
+        Self { gamut, r, g, b }
+    }
+
+    pub fn from_linrgb(gamut: Gamut, linrgb: [f64; 3]) -> Self {
+        Self {
+            gamut,
+            r: gamut.delinearized(linrgb[0]),
+            g: gamut.delinearized(linrgb[1]),
+            b: gamut.delinearized(linrgb[2]),
+        }
+    }
+
+    pub fn linrgb(&self) -> [f64; 3] {
+        [
+            self.gamut.linearized(self.r),
+            self.gamut.linearized(self.g),
+            self.gamut.linearized(self.b),
+        ]
+    }
+
+    /**
+     * Re-expresses the color in another gamut. Channels are not clipped, so
+     * colors outside the target gamut end up outside 0..1.
+     */
+    pub fn to_gamut(&self, gamut: Gamut) -> Self {
+        if gamut == self.gamut {
+            return *self;
+        }
+        let xyz = self.gamut.xyz_from_linrgb(self.linrgb());
+        Self::from_linrgb(gamut, gamut.linrgb_from_xyz(xyz))
+    }
+}
+
+impl From<GamutRgb> for Xyz {
+    fn from(rgb: GamutRgb) -> Self {
+        let [x, y, z] = rgb.gamut.xyz_from_linrgb(rgb.linrgb());
+        Xyz::new(x, y, z)
+    }
+}
+
+impl fmt::Display for GamutRgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "color({} {:.4} {:.4} {:.4})",
+            self.gamut.css_name(),
+            self.r,
+            self.g,
+            self.b
+        )
+    }
+}
@@ -1,16 +1,39 @@
-use crate::utils::color::lstar_from_argb;
+use crate::{
+    space::{
+        rgb::Rgb,
+        xyz::{LinearRgb, Xyz},
+    },
+    utils::color::{alpha_from_argb, argb_with_alpha, lstar_from_argb, lstar_from_y},
+};
 
-use self::{cam16::Cam16, solver::solve_to_int};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+use self::{
+    cam16::Cam16,
+    solver::{solve_to_int, solve_to_rgb},
+};
+
+pub mod batch;
 pub mod cam16;
+pub mod gamut;
 pub mod solver;
 pub mod vc;
 
+/**
+ * With the `serde` feature, serialized as hue, chroma, tone and alpha, and
+ * solved again when deserialized.
+ */
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "HctValue", into = "HctValue"))]
 pub struct HCT {
     hue: f64,
     chroma: f64,
     tone: f64,
     argb: u32,
+    rgb: Rgb<f64>,
 }
 
 impl HCT {
@@ -18,12 +41,22 @@ impl HCT {
         solve_to_int(hue, chroma, tone).into()
     }
 
-    fn set(&mut self, argb: u32) {
-        let target: HCT = argb.into();
-        self.argb = argb;
-        self.hue = target.hue;
-        self.chroma = target.chroma;
-        self.tone = target.tone;
+    /**
+     * Like [`HCT::new`], but keeps the solved color at full precision instead
+     * of rounding it to ARGB first.
+     */
+    pub fn new_unquantized(hue: f64, chroma: f64, tone: f64) -> Self {
+        solve_to_rgb(hue, chroma, tone).into()
+    }
+
+    /**
+     * Replaces the color with a solved one, keeping alpha. Only
+     * [`HCT::argb`] is quantized, so edits don't lose precision.
+     */
+    fn set(&mut self, rgb: Rgb<f64>) {
+        let alpha = self.alpha();
+        *self = rgb.into();
+        self.set_alpha(alpha);
     }
 
     pub fn hue(&self) -> f64 {
@@ -31,7 +64,7 @@ impl HCT {
     }
 
     pub fn set_hue(&mut self, hue: f64) {
-        self.set(solve_to_int(hue, self.chroma, self.tone))
+        self.set(solve_to_rgb(hue, self.chroma, self.tone))
     }
 
     pub fn chroma(&self) -> f64 {
@@ -39,7 +72,7 @@ impl HCT {
     }
 
     pub fn set_chroma(&mut self, chroma: f64) {
-        self.set(solve_to_int(self.hue, chroma, self.tone))
+        self.set(solve_to_rgb(self.hue, chroma, self.tone))
     }
 
     pub fn tone(&self) -> f64 {
@@ -47,36 +80,103 @@ impl HCT {
     }
 
     pub fn set_tone(&mut self, tone: f64) {
-        self.set(solve_to_int(self.hue, self.chroma, tone))
+        self.set(solve_to_rgb(self.hue, self.chroma, tone))
     }
 
     pub fn argb(&self) -> u32 {
         self.argb
     }
 
+    /**
+     * The alpha channel, carried along unchanged when hue, chroma or tone are
+     * set. Colors created from hue, chroma and tone are opaque.
+     */
+    pub fn alpha(&self) -> u8 {
+        alpha_from_argb(self.argb) as u8
+    }
+
+    pub fn set_alpha(&mut self, alpha: u8) {
+        self.argb = argb_with_alpha(self.argb, alpha as u32);
+    }
+
     pub fn set_argb(&mut self, argb: u32) {
-        self.set(argb)
+        *self = argb.into();
+    }
+
+    /**
+     * The unquantized sRGB color. For colors created from ARGB this is the
+     * same color as [`HCT::argb`].
+     */
+    pub fn rgb(&self) -> Rgb<f64> {
+        self.rgb
     }
 }
 
 impl From<u32> for HCT {
     fn from(argb: u32) -> Self {
-        let Cam16 {
-            hue,
-            chroma,
-            j,
-            q,
-            m,
-            s,
-            jstar,
-            astar,
-            bstar,
-        } = argb.into();
+        let Cam16 { hue, chroma, .. } = argb.into();
         Self {
             hue,
             chroma,
             tone: lstar_from_argb(argb),
             argb,
+            rgb: argb.into(),
+        }
+    }
+}
+
+impl From<Rgb<f64>> for HCT {
+    fn from(rgb: Rgb<f64>) -> Self {
+        let Cam16 { hue, chroma, .. } = rgb.into();
+        let Xyz { y, .. } = LinearRgb::from(rgb).into();
+        Self {
+            hue,
+            chroma,
+            tone: lstar_from_y(y),
+            argb: rgb.into(),
+            rgb,
         }
     }
 }
+
+impl From<HCT> for u32 {
+    fn from(hct: HCT) -> Self {
+        hct.argb
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct HctValue {
+    hue: f64,
+    chroma: f64,
+    tone: f64,
+    #[serde(default = "opaque")]
+    alpha: u8,
+}
+
+#[cfg(feature = "serde")]
+fn opaque() -> u8 {
+    255
+}
+
+#[cfg(feature = "serde")]
+impl From<HCT> for HctValue {
+    fn from(hct: HCT) -> Self {
+        Self {
+            hue: hct.hue,
+            chroma: hct.chroma,
+            tone: hct.tone,
+            alpha: hct.alpha(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<HctValue> for HCT {
+    fn from(value: HctValue) -> Self {
+        let mut hct = HCT::new(value.hue, value.chroma, value.tone);
+        hct.set_alpha(value.alpha);
+        hct
+    }
+}
@@ -1,11 +1,17 @@
-use std::ops::Mul;
-
-use crate::utils::{
-    color::{argb_from_linrgb, argb_from_lstar, y_from_lstar},
-    math::{matrix_multiply, sanitize_degrees_double},
+use crate::{
+    space::rgb::Rgb,
+    utils::{
+        color::{argb_from_linrgb, argb_from_lstar, y_from_lstar},
+        math::{matrix_multiply, sanitize_degrees_double},
+    },
 };
 
-use super::{cam16::Cam16, vc::ViewingConditions};
+use super::{
+    cam16::Cam16,
+    gamut::{Gamut, GamutRgb},
+    vc::ViewingConditions,
+};
+import_float!();
 
 const SCALED_DISCOUNT_FROM_LINRGB: [[f64; 3]; 3] = [
     [
@@ -121,36 +127,103 @@ const CRITICAL_PLANES: [f64; 255] = [
 
 const Y_FROM_LINRGB: [f64; 3] = [0.2126, 0.7152, 0.0722];
 
-fn sanitize_radians(angle: f64) -> f64 {
-    (angle + std::f64::consts::PI) % (std::f64::consts::TAU)
+const SCALED_DISCOUNT_FROM_DISPLAY_P3: [[f64; 3]; 3] = [
+    [0.001365186008975879, 0.002197913955194553, 0.000306933472781581],
+    [0.000590096826958192, 0.002945445185722102, 0.00035923703776282844],
+    [3.6998061314053885e-05, 0.00027686712889908176, 0.0036227510260559543],
+];
+
+const DISPLAY_P3_FROM_SCALED_DISCOUNT: [[f64; 3]; 3] = [
+    [1081.0212157921242, -805.5659200970712, -11.707258034085289],
+    [-217.25263576454694, 504.59601266091676, -31.62996023323968],
+    [5.563292685934278, -30.33652359343548, 278.57019013151097],
+];
+
+const Y_FROM_DISPLAY_P3: [f64; 3] = [0.22897456406974867, 0.6917385218365062, 0.079286914093745];
+
+const SCALED_DISCOUNT_FROM_REC2020: [[f64; 3]; 3] = [
+    [0.0016915821506322116, 0.0019732461008678467, 0.00020520518545195406],
+    [0.0006015248861595093, 0.002995120504267025, 0.0002981336600165874],
+    [4.1853704988836766e-05, 0.00021640974466585291, 0.0036783527666143993],
+];
+
+const REC2020_FROM_SCALED_DISCOUNT: [[f64; 3]; 3] = [
+    [772.0283450161473, -508.49385756542017, -1.855473168103341],
+    [-155.08408813675393, 437.9887767598386, -26.847652811109896],
+    [0.339679579247057, -19.98250089253403, 273.4614692875566],
+];
+
+const Y_FROM_REC2020: [f64; 3] = [0.262700212011267, 0.6779980715188708, 0.05930171646986195];
+
+/**
+ * The gamut dependent constants of the solver. The matrices fold the
+ * chromatic adaptation of the default viewing conditions into the gamut's
+ * linear RGB to XYZ transform.
+ */
+struct SolverGamut {
+    gamut: Gamut,
+    scaled_discount_from_linrgb: [[f64; 3]; 3],
+    linrgb_from_scaled_discount: [[f64; 3]; 3],
+    y_from_linrgb: [f64; 3],
 }
 
-fn true_delinearized(rgb_component: f64) -> f64 {
-    let normalized = rgb_component / 100.0;
-    if normalized <= 0.0031308 {
-        normalized * 12.92 * 255.0
-    } else {
-        (1.055 * normalized.powf(1.0 / 2.4) - 0.055) * 255.0
+const SRGB: SolverGamut = SolverGamut {
+    gamut: Gamut::Srgb,
+    scaled_discount_from_linrgb: SCALED_DISCOUNT_FROM_LINRGB,
+    linrgb_from_scaled_discount: LINRGB_FROM_SCALED_DISCOUNT,
+    y_from_linrgb: Y_FROM_LINRGB,
+};
+
+const DISPLAY_P3: SolverGamut = SolverGamut {
+    gamut: Gamut::DisplayP3,
+    scaled_discount_from_linrgb: SCALED_DISCOUNT_FROM_DISPLAY_P3,
+    linrgb_from_scaled_discount: DISPLAY_P3_FROM_SCALED_DISCOUNT,
+    y_from_linrgb: Y_FROM_DISPLAY_P3,
+};
+
+const REC2020: SolverGamut = SolverGamut {
+    gamut: Gamut::Rec2020,
+    scaled_discount_from_linrgb: SCALED_DISCOUNT_FROM_REC2020,
+    linrgb_from_scaled_discount: REC2020_FROM_SCALED_DISCOUNT,
+    y_from_linrgb: Y_FROM_REC2020,
+};
+
+impl SolverGamut {
+    fn of(gamut: Gamut) -> &'static Self {
+        match gamut {
+            Gamut::Srgb => &SRGB,
+            Gamut::DisplayP3 => &DISPLAY_P3,
+            Gamut::Rec2020 => &REC2020,
+        }
+    }
+
+    fn true_delinearized(&self, rgb_component: f64) -> f64 {
+        self.gamut.delinearized(rgb_component) * 255.0
+    }
+
+    /**
+     * The linear component at which the encoded component crosses the
+     * midpoint between two 8-bit steps.
+     */
+    fn critical_plane(&self, plane: isize) -> f64 {
+        match self.gamut {
+            Gamut::Srgb | Gamut::DisplayP3 => CRITICAL_PLANES[plane as usize],
+            Gamut::Rec2020 => self.gamut.linearized((plane as f64 + 0.5) / 255.0),
+        }
     }
 }
 
+fn sanitize_radians(angle: f64) -> f64 {
+    (angle + core::f64::consts::PI * 8.0) % core::f64::consts::TAU
+}
+
 fn chromatic_adaptation(component: f64) -> f64 {
     let af = component.abs().powf(0.42);
     component.signum() * 400.0 * af / (af + 27.13)
 }
 
-fn hue_of(linrgb: [f64; 3]) -> f64 {
-    let scaled_discount = [
-        linrgb[0] * SCALED_DISCOUNT_FROM_LINRGB[0][0]
-            + linrgb[0] * SCALED_DISCOUNT_FROM_LINRGB[1][0]
-            + linrgb[0] * SCALED_DISCOUNT_FROM_LINRGB[2][0],
-        linrgb[1] * SCALED_DISCOUNT_FROM_LINRGB[0][1]
-            + linrgb[1] * SCALED_DISCOUNT_FROM_LINRGB[1][0]
-            + linrgb[1] * SCALED_DISCOUNT_FROM_LINRGB[2][1],
-        linrgb[2] * SCALED_DISCOUNT_FROM_LINRGB[0][2]
-            + linrgb[2] * SCALED_DISCOUNT_FROM_LINRGB[1][0]
-            + linrgb[2] * SCALED_DISCOUNT_FROM_LINRGB[2][2],
-    ];
+fn hue_of(linrgb: [f64; 3], gamut: &SolverGamut) -> f64 {
+    let scaled_discount = matrix_multiply(linrgb, gamut.scaled_discount_from_linrgb);
 
     let r_a = chromatic_adaptation(scaled_discount[0]);
     let g_a = chromatic_adaptation(scaled_discount[1]);
@@ -159,7 +232,7 @@ fn hue_of(linrgb: [f64; 3]) -> f64 {
     let a = (11.0 * r_a + -12.0 * g_a + b_a) / 11.0;
     let b = (r_a + g_a - 2.0 * b_a) / 9.0;
 
-    a.atan2(b)
+    b.atan2(a)
 }
 
 fn are_in_cyclic_order(a: f64, b: f64, c: f64) -> bool {
@@ -184,44 +257,44 @@ fn set_coordinate(source: [f64; 3], coordinate: f64, target: [f64; 3], axis: usi
 }
 
 fn is_bounded(x: f64) -> bool {
-    0.0 <= x && x <= 100.0
+    (0.0..=100.0).contains(&x)
 }
 
-fn nth_vertex(y: f64, n: usize) -> [f64; 3] {
-    let k_r = Y_FROM_LINRGB[0];
-    let k_g = Y_FROM_LINRGB[1];
-    let k_b = Y_FROM_LINRGB[2];
+fn nth_vertex(y: f64, n: usize, gamut: &SolverGamut) -> [f64; 3] {
+    let k_r = gamut.y_from_linrgb[0];
+    let k_g = gamut.y_from_linrgb[1];
+    let k_b = gamut.y_from_linrgb[2];
     let coord_a = if n % 4 <= 1 { 0.0 } else { 100.0 };
-    let coord_b = if n % 2 == 0 { 0.0 } else { 100.0 };
+    let coord_b = if n.is_multiple_of(2) { 0.0 } else { 100.0 };
 
     if n < 4 {
         let (g, b) = (coord_a, coord_b);
         let r = (y - g * k_g - b * k_b) / k_r;
         if is_bounded(r) {
-            return [r, g, b];
+            [r, g, b]
         } else {
-            return [-1.0, -1.0, -1.0];
+            [-1.0, -1.0, -1.0]
         }
     } else if n < 8 {
         let (b, r) = (coord_a, coord_b);
         let g = (y - r * k_r - b * k_b) / k_g;
         if is_bounded(g) {
-            return [r, g, b];
+            [r, g, b]
         } else {
-            return [-1.0, -1.0, -1.0];
+            [-1.0, -1.0, -1.0]
         }
     } else {
         let (r, g) = (coord_a, coord_b);
         let b = (y - r * k_r - g * k_g) / k_b;
         if is_bounded(b) {
-            return [r, g, b];
+            [r, g, b]
         } else {
-            return [-1.0, -1.0, -1.0];
+            [-1.0, -1.0, -1.0]
         }
     }
 }
 
-fn bisect_to_segment(y: f64, target_hue: f64) -> [[f64; 3]; 2] {
+fn bisect_to_segment(y: f64, target_hue: f64, gamut: &SolverGamut) -> [[f64; 3]; 2] {
     let mut left = [-1.0, -1.0, -1.0];
     let mut left_hue = 0.0;
     let mut right = [-1.0, -1.0, -1.0];
@@ -231,11 +304,11 @@ fn bisect_to_segment(y: f64, target_hue: f64) -> [[f64; 3]; 2] {
     let mut uncut = true;
 
     for n in 0..12usize {
-        let mid = nth_vertex(y, n);
+        let mid = nth_vertex(y, n, gamut);
         if mid[0] < 0.0 {
             continue;
         }
-        let mid_hue = hue_of(mid);
+        let mid_hue = hue_of(mid, gamut);
         if !initialized {
             left = mid;
             right = mid;
@@ -276,37 +349,39 @@ fn critical_plane_above(x: f64) -> isize {
     (x - 0.5).ceil() as isize
 }
 
-fn bisect_to_limit(y: f64, target_hue: f64) -> [f64; 3] {
-    let segment = bisect_to_segment(y, target_hue);
+fn bisect_to_limit(y: f64, target_hue: f64, gamut: &SolverGamut) -> [f64; 3] {
+    let segment = bisect_to_segment(y, target_hue, gamut);
     let mut left = segment[0];
-    let mut left_hue = hue_of(left);
+    let mut left_hue = hue_of(left, gamut);
     let mut right = segment[1];
     for axis in 0..3 {
         if left[axis] != right[axis] {
-            let mut l_plane = -1;
-            let mut r_plane = 255;
-            if left[axis] < right[axis] {
-                l_plane = critical_plane_below(true_delinearized(left[axis]));
-                r_plane = critical_plane_above(true_delinearized(right[axis]));
+            let (mut l_plane, mut r_plane) = if left[axis] < right[axis] {
+                (
+                    critical_plane_below(gamut.true_delinearized(left[axis])),
+                    critical_plane_above(gamut.true_delinearized(right[axis])),
+                )
             } else {
-                l_plane = critical_plane_above(true_delinearized(left[axis]));
-                r_plane = critical_plane_below(true_delinearized(right[axis]));
-            }
-            for i in 0..8 {
+                (
+                    critical_plane_above(gamut.true_delinearized(left[axis])),
+                    critical_plane_below(gamut.true_delinearized(right[axis])),
+                )
+            };
+            for _ in 0..8 {
                 if (r_plane - l_plane).abs() <= 1 {
                     break;
                 } else {
-                    let m_plane = ((l_plane + r_plane) / 2).abs();
-                    let mid_plane_coordinate = CRITICAL_PLANES[m_plane as usize];
+                    let m_plane = (l_plane + r_plane).div_euclid(2);
+                    let mid_plane_coordinate = gamut.critical_plane(m_plane);
                     let mid = set_coordinate(left, mid_plane_coordinate, right, axis);
-                    let mid_hue = hue_of(mid);
+                    let mid_hue = hue_of(mid, gamut);
                     if are_in_cyclic_order(left_hue, target_hue, mid_hue) {
                         right = mid;
                         r_plane = m_plane;
                     } else {
                         left = mid;
                         left_hue = mid_hue;
-                        l_plane;
+                        l_plane = m_plane;
                     }
                 }
             }
@@ -321,9 +396,14 @@ fn inverse_chromatic_adaptation(adapted: f64) -> f64 {
     adapted.signum() * base.powf(1.0 / 0.42)
 }
 
-fn find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> u32 {
+fn find_result_by_j(
+    hue_radians: f64,
+    chroma: f64,
+    y: f64,
+    gamut: &SolverGamut,
+) -> Option<[f64; 3]> {
     let mut j = y.sqrt() * 11.0;
-    let viewing_conditions = ViewingConditions::default();
+    let viewing_conditions = &ViewingConditions::DEFAULT;
     let t_inner_coeff = 1.0 / (1.64 - 0.29f64.powf(viewing_conditions.n)).powf(0.73);
     let e_hue = 0.25 * ((hue_radians + 2.0).cos() + 3.8);
     let p1 = e_hue * (50000.0 / 13.0) * viewing_conditions.nc * viewing_conditions.ncb;
@@ -340,7 +420,7 @@ fn find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> u32 {
         let ac =
             viewing_conditions.aw * j_norm.powf(1.0 / viewing_conditions.c / viewing_conditions.z);
         let p2 = ac / viewing_conditions.nbb;
-        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 * 11.0 * t * h_cos + 108.0 * t * h_sin);
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * h_cos + 108.0 * t * h_sin);
         let a = gamma * h_cos;
         let b = gamma * h_sin;
         let r_a = (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0;
@@ -351,45 +431,71 @@ fn find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> u32 {
         let b_c_scaled = inverse_chromatic_adaptation(b_a);
         let linrgb = matrix_multiply(
             [r_c_scaled, g_c_scaled, b_c_scaled],
-            LINRGB_FROM_SCALED_DISCOUNT,
+            gamut.linrgb_from_scaled_discount,
         );
         if linrgb[0] < 0.0 || linrgb[1] < 0.0 || linrgb[2] < 0.0 {
-            return 0;
+            return None;
         }
-        let k_r = Y_FROM_LINRGB[0];
-        let k_g = Y_FROM_LINRGB[1];
-        let k_b = Y_FROM_LINRGB[2];
+        let k_r = gamut.y_from_linrgb[0];
+        let k_g = gamut.y_from_linrgb[1];
+        let k_b = gamut.y_from_linrgb[2];
         let fnj = k_r * linrgb[0] + k_g * linrgb[1] + k_b * linrgb[2];
         if fnj <= 0.0 {
-            return 0;
+            return None;
         }
         if iter_round == 4 || (fnj - y).abs() < 0.002 {
             if linrgb[0] > 100.01 || linrgb[1] > 100.01 || linrgb[2] > 100.01 {
-                return 0;
+                return None;
             }
-            return argb_from_linrgb(linrgb);
+            return Some(linrgb);
         }
 
-        j = j - (fnj - y) * j / (2.0 * fnj);
+        j -= (fnj - y) * j / (2.0 * fnj);
     }
-    return 0;
+    None
 }
 
-pub fn solve_to_int(mut hue_degrees: f64, chroma: f64, lstar: f64) -> u32 {
-    if chroma < 0.0001 || lstar < 0.0001 || lstar > 99.9999 {
+fn is_achromatic(chroma: f64, lstar: f64) -> bool {
+    chroma < 0.0001 || !(0.0001..=99.9999).contains(&lstar)
+}
+
+fn solve_to_linrgb(hue_degrees: f64, chroma: f64, lstar: f64, gamut: &SolverGamut) -> [f64; 3] {
+    let hue_degrees = sanitize_degrees_double(hue_degrees);
+    let hue_radians = hue_degrees / 180.0 * core::f64::consts::PI;
+    let y = y_from_lstar(lstar);
+    find_result_by_j(hue_radians, chroma, y, gamut)
+        .unwrap_or_else(|| bisect_to_limit(y, hue_radians, gamut))
+}
+
+pub fn solve_to_int(hue_degrees: f64, chroma: f64, lstar: f64) -> u32 {
+    if is_achromatic(chroma, lstar) {
         return argb_from_lstar(lstar);
     }
-    hue_degrees = sanitize_degrees_double(hue_degrees);
-    let hue_radians = hue_degrees / 180.0 * std::f64::consts::PI;
-    let y = y_from_lstar(lstar);
-    let exact_answer = find_result_by_j(hue_radians, chroma, y);
-    if exact_answer != 0 {
-        return exact_answer;
+    argb_from_linrgb(solve_to_linrgb(hue_degrees, chroma, lstar, &SRGB))
+}
+
+/**
+ * Like [`solve_to_int`], but returns unquantized sRGB.
+ */
+pub fn solve_to_rgb(hue_degrees: f64, chroma: f64, lstar: f64) -> Rgb<f64> {
+    solve_to_gamut(hue_degrees, chroma, lstar, Gamut::Srgb).into()
+}
+
+/**
+ * Like [`solve_to_int`], but maps into the given gamut and keeps the result
+ * unquantized. Wider gamuts reach higher chroma for the same hue and tone.
+ */
+pub fn solve_to_gamut(hue_degrees: f64, chroma: f64, lstar: f64, gamut: Gamut) -> GamutRgb {
+    if is_achromatic(chroma, lstar) {
+        let y = y_from_lstar(lstar).clamp(0.0, 100.0);
+        return GamutRgb::from_linrgb(gamut, [y, y, y]);
     }
-    let linrgb = bisect_to_limit(y, hue_radians);
-    argb_from_linrgb(linrgb)
+    GamutRgb::from_linrgb(
+        gamut,
+        solve_to_linrgb(hue_degrees, chroma, lstar, SolverGamut::of(gamut)),
+    )
 }
 
-pub fn solve_to_cam(mut hue_degrees: f64, chroma: f64, lstar: f64) -> Cam16 {
+pub fn solve_to_cam(hue_degrees: f64, chroma: f64, lstar: f64) -> Cam16 {
     solve_to_int(hue_degrees, chroma, lstar).into()
 }
@@ -1,6 +1,9 @@
-use crate::utils::{self, color::y_from_lstar, math::lerp};
+use crate::utils::{color::y_from_lstar, math::lerp};
+import_float!();
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-const PI_FRAC_200: f64 = 200.0 / std::f64::consts::PI;
+const PI_FRAC_200: f64 = 200.0 / core::f64::consts::PI;
 
 /**
  * In traditional color spaces, a color can be identified solely by the
@@ -15,6 +18,8 @@ const PI_FRAC_200: f64 = 200.0 / std::f64::consts::PI;
  * This class caches intermediate values of the CAM16 conversion process that
  * depend only on viewing conditions, enabling speed ups.
  */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ViewingConditions {
     pub n: f64,
     pub aw: f64,
@@ -28,9 +33,29 @@ pub struct ViewingConditions {
     pub z: f64,
 }
 
+impl ViewingConditions {
+    /**
+     * The standard viewing conditions, as built by
+     * `ViewingConditionsBuilder::new().build()`. Precomputed so conversions
+     * can borrow them instead of building them on every call.
+     */
+    pub const DEFAULT: ViewingConditions = ViewingConditions {
+        n: 0.18418651851244416,
+        aw: 29.980997194447333,
+        nbb: 1.0169191804458755,
+        ncb: 1.0169191804458755,
+        c: 0.69,
+        nc: 1.0,
+        rgb_d: [1.02117770275752, 0.9863077294280124, 0.9339605082802299],
+        fl: 0.3884814537800353,
+        f_l_root: 0.7894826179304937,
+        z: 1.909169568483652,
+    };
+}
+
 impl Default for ViewingConditions {
     fn default() -> Self {
-        ViewingConditionsBuilder::new().build()
+        Self::DEFAULT
     }
 }
 
@@ -42,6 +67,12 @@ pub struct ViewingConditionsBuilder {
     discounting_illumination: bool,
 }
 
+impl Default for ViewingConditionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ViewingConditionsBuilder {
     pub fn new() -> Self {
         Self {
@@ -80,9 +111,9 @@ impl ViewingConditionsBuilder {
 
     pub fn build(self) -> ViewingConditions {
         let xyz = self.white_point;
-        let rW = xyz[0] * 0.401288 + xyz[1] * 0.650173 + xyz[2] * -0.051461;
-        let gW = xyz[0] * -0.250268 + xyz[1] * 1.204414 + xyz[2] * 0.045854;
-        let bW = xyz[0] * -0.002079 + xyz[1] * 0.048952 + xyz[2] * 0.953127;
+        let r_w = xyz[0] * 0.401288 + xyz[1] * 0.650173 + xyz[2] * -0.051461;
+        let g_w = xyz[0] * -0.250268 + xyz[1] * 1.204414 + xyz[2] * 0.045854;
+        let b_w = xyz[0] * -0.002079 + xyz[1] * 0.048952 + xyz[2] * 0.953127;
         let f = 0.8 + self.surround / 10.0;
         let c = if f >= 0.9 {
             lerp(0.59, 0.69, (f - 0.9) * 10.0)
@@ -92,41 +123,35 @@ impl ViewingConditionsBuilder {
         let d = if self.discounting_illumination {
             1.0
         } else {
-            let d = f * (1.0 - (1.0 / 3.6) * ((-self.adapting_luminance - 42.0) / 92.0)).exp();
-            if d > 1.0 {
-                1.0
-            } else if d < 0.0 {
-                0.0
-            } else {
-                d
-            }
+            let d = f * (1.0 - (1.0 / 3.6) * ((-self.adapting_luminance - 42.0) / 92.0).exp());
+            d.clamp(0.0, 1.0)
         };
         let nc = f;
         let rgb_d = [
-            d * (100.0 / rW) + 1.0 - d,
-            d * (100.0 / gW) + 1.0 - d,
-            d * (100.0 / bW) + 1.0 - d,
+            d * (100.0 / r_w) + 1.0 - d,
+            d * (100.0 / g_w) + 1.0 - d,
+            d * (100.0 / b_w) + 1.0 - d,
         ];
         let k = 1.0 / (5.0 * self.adapting_luminance + 1.0);
         let k4 = k * k * k * k;
         let k4_f = 1.0 - k4;
         let fl = k4 * self.adapting_luminance
-            + 0.1 * k4_f * k4_f * (0.5 * self.adapting_luminance).cbrt();
+            + 0.1 * k4_f * k4_f * (5.0 * self.adapting_luminance).cbrt();
         let n = y_from_lstar(self.background_lstar) / self.white_point[1];
         let z = 1.48 + n.sqrt();
         let nbb = 0.725 / n.powf(0.2);
         let ncb = nbb;
         let rgb_afactors = [
-            ((fl * rgb_d[0] * rW) / 100.0).powf(0.42),
-            ((fl * rgb_d[1] * gW) / 100.0).powf(0.42),
-            ((fl * rgb_d[2] * bW) / 100.0).powf(0.42),
+            ((fl * rgb_d[0] * r_w) / 100.0).powf(0.42),
+            ((fl * rgb_d[1] * g_w) / 100.0).powf(0.42),
+            ((fl * rgb_d[2] * b_w) / 100.0).powf(0.42),
         ];
-        let rgbA = [
+        let rgb_a = [
             (400.0 * rgb_afactors[0]) / (rgb_afactors[0] + 27.13),
             (400.0 * rgb_afactors[1]) / (rgb_afactors[1] + 27.13),
             (400.0 * rgb_afactors[2]) / (rgb_afactors[2] + 27.13),
         ];
-        let aw = (2.0 * rgbA[0] + rgbA[1] + 0.05 * rgbA[2]) * nbb;
+        let aw = (2.0 * rgb_a[0] + rgb_a[1] + 0.05 * rgb_a[2]) * nbb;
         ViewingConditions {
             n,
             aw,
@@ -0,0 +1,94 @@
+//! `bevy` colors from a [`Scheme`], behind the `bevy` feature.
+
+use ::bevy_color::Color;
+use ::bevy_ecs::resource::Resource;
+
+use crate::{
+    hct::HCT,
+    scheme::Scheme,
+    utils::color::{alpha_from_argb, blue_from_argb, green_from_argb, red_from_argb},
+};
+
+fn color(argb: u32) -> Color {
+    Color::srgba_u8(
+        red_from_argb(argb) as u8,
+        green_from_argb(argb) as u8,
+        blue_from_argb(argb) as u8,
+        alpha_from_argb(argb) as u8,
+    )
+}
+
+impl From<HCT> for Color {
+    fn from(hct: HCT) -> Self {
+        color(hct.argb())
+    }
+}
+
+/**
+ * Every role of a [`Scheme`] as a bevy [`Color`], to insert as a resource
+ * and read from UI systems.
+ */
+#[derive(Resource, Debug, Copy, Clone, PartialEq)]
+pub struct SchemeColors {
+    pub primary: Color,
+    pub on_primary: Color,
+    pub primary_container: Color,
+    pub on_primary_container: Color,
+    pub secondary: Color,
+    pub on_secondary: Color,
+    pub secondary_container: Color,
+    pub on_secondary_container: Color,
+    pub tertiary: Color,
+    pub on_tertiary: Color,
+    pub tertiary_container: Color,
+    pub on_tertiary_container: Color,
+    pub error: Color,
+    pub on_error: Color,
+    pub error_container: Color,
+    pub on_error_container: Color,
+    pub background: Color,
+    pub on_background: Color,
+    pub surface: Color,
+    pub on_surface: Color,
+    pub surface_variant: Color,
+    pub on_surface_variant: Color,
+    pub outline: Color,
+    pub shadow: Color,
+    pub inverse_surface: Color,
+    pub inverse_on_surface: Color,
+    pub inverse_primary: Color,
+}
+
+impl From<&Scheme> for SchemeColors {
+    fn from(scheme: &Scheme) -> Self {
+        Self {
+            primary: color(scheme.primary()),
+            on_primary: color(scheme.on_primary()),
+            primary_container: color(scheme.primary_container()),
+            on_primary_container: color(scheme.on_primary_container()),
+            secondary: color(scheme.secondary()),
+            on_secondary: color(scheme.on_secondary()),
+            secondary_container: color(scheme.secondary_container()),
+            on_secondary_container: color(scheme.on_secondary_container()),
+            tertiary: color(scheme.tertiary()),
+            on_tertiary: color(scheme.on_tertiary()),
+            tertiary_container: color(scheme.tertiary_container()),
+            on_tertiary_container: color(scheme.on_tertiary_container()),
+            error: color(scheme.error()),
+            on_error: color(scheme.on_error()),
+            error_container: color(scheme.error_container()),
+            on_error_container: color(scheme.on_error_container()),
+            background: color(scheme.background()),
+            on_background: color(scheme.on_background()),
+            surface: color(scheme.surface()),
+            on_surface: color(scheme.on_surface()),
+            surface_variant: color(scheme.surface_variant()),
+            on_surface_variant: color(scheme.on_surface_variant()),
+            outline: color(scheme.outline()),
+            shadow: color(scheme.shadow()),
+            inverse_surface: color(scheme.inverse_surface()),
+            inverse_on_surface: color(scheme.inverse_on_surface()),
+            inverse_primary: color(scheme.inverse_primary()),
+        }
+    }
+}
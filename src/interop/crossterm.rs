@@ -0,0 +1,34 @@
+//! `crossterm` colors, behind the `crossterm` feature.
+
+use ::crossterm::style::Color;
+
+use crate::{
+    hct::HCT,
+    terminal::Ansi256,
+    utils::color::{blue_from_argb, green_from_argb, red_from_argb},
+};
+
+/**
+ * A true color [`Color::Rgb`]. Terminals have no alpha, so it is dropped.
+ */
+pub fn rgb(argb: u32) -> Color {
+    Color::Rgb {
+        r: red_from_argb(argb) as u8,
+        g: green_from_argb(argb) as u8,
+        b: blue_from_argb(argb) as u8,
+    }
+}
+
+/**
+ * The nearest color of `ansi` as a [`Color::AnsiValue`], for terminals
+ * without true color.
+ */
+pub fn ansi_value(argb: u32, ansi: &Ansi256) -> Color {
+    Color::AnsiValue(ansi.nearest(argb))
+}
+
+impl From<HCT> for Color {
+    fn from(hct: HCT) -> Self {
+        rgb(hct.argb())
+    }
+}
@@ -0,0 +1,99 @@
+//! `egui` visuals from a [`Scheme`], behind the `egui` feature.
+
+use ::egui::{style::WidgetVisuals, Color32, Stroke, Visuals};
+
+use crate::{
+    blend::BlendSpace,
+    hct::HCT,
+    scheme::{Scheme, StateColors},
+    utils::color::{alpha_from_argb, blue_from_argb, green_from_argb, red_from_argb},
+};
+
+use super::{status_color, WARNING};
+
+fn color32(argb: u32) -> Color32 {
+    Color32::from_rgba_unmultiplied(
+        red_from_argb(argb) as u8,
+        green_from_argb(argb) as u8,
+        blue_from_argb(argb) as u8,
+        alpha_from_argb(argb) as u8,
+    )
+}
+
+fn widget(base: WidgetVisuals, fill: u32, stroke: u32, content: u32) -> WidgetVisuals {
+    WidgetVisuals {
+        bg_fill: color32(fill),
+        weak_bg_fill: color32(fill),
+        bg_stroke: Stroke::new(base.bg_stroke.width, color32(stroke)),
+        fg_stroke: Stroke::new(base.fg_stroke.width, color32(content)),
+        ..base
+    }
+}
+
+/**
+ * Egui's light or dark visuals, depending on the scheme's background, with
+ * every color taken from the scheme. Widgets sit on surface variant, with
+ * Material state layers for hover and press, and open widgets use the
+ * secondary container.
+ */
+impl From<&Scheme> for Visuals {
+    fn from(scheme: &Scheme) -> Self {
+        let dark = HCT::from(scheme.background()).tone() < 50.0;
+        let base = if dark { Visuals::dark() } else { Visuals::light() };
+        let states = StateColors::new(
+            scheme.on_surface_variant(),
+            scheme.surface_variant(),
+            BlendSpace::Srgb,
+        );
+        let mut visuals = Visuals {
+            dark_mode: dark,
+            override_text_color: Some(color32(scheme.on_surface())),
+            hyperlink_color: color32(scheme.primary()),
+            faint_bg_color: color32(scheme.surface_at_elevation(1)),
+            extreme_bg_color: color32(scheme.background()),
+            code_bg_color: color32(scheme.surface_variant()),
+            warn_fg_color: color32(status_color(scheme, WARNING)),
+            error_fg_color: color32(scheme.error()),
+            window_fill: color32(scheme.surface_at_elevation(3)),
+            window_stroke: Stroke::new(base.window_stroke.width, color32(scheme.outline())),
+            panel_fill: color32(scheme.surface()),
+            ..base
+        };
+        visuals.selection.bg_fill = color32(scheme.primary_container());
+        visuals.selection.stroke.color = color32(scheme.on_primary_container());
+        visuals.text_cursor.stroke.color = color32(scheme.primary());
+
+        let widgets = &mut visuals.widgets;
+        widgets.noninteractive = widget(
+            widgets.noninteractive,
+            scheme.surface(),
+            scheme.surface_variant(),
+            scheme.on_surface(),
+        );
+        widgets.inactive = widget(
+            widgets.inactive,
+            states.enabled,
+            scheme.surface_variant(),
+            scheme.on_surface_variant(),
+        );
+        widgets.hovered = widget(
+            widgets.hovered,
+            states.hover,
+            scheme.outline(),
+            scheme.on_surface(),
+        );
+        widgets.active = widget(
+            widgets.active,
+            states.pressed,
+            scheme.outline(),
+            scheme.on_surface(),
+        );
+        widgets.open = widget(
+            widgets.open,
+            scheme.secondary_container(),
+            scheme.outline(),
+            scheme.on_secondary_container(),
+        );
+        visuals
+    }
+}
@@ -0,0 +1,52 @@
+//! `iced` palettes and themes from a [`Scheme`], behind the `iced` feature.
+
+use alloc::borrow::Cow;
+
+use ::iced_core::{
+    theme::{Palette, Theme},
+    Color,
+};
+
+use crate::{
+    scheme::Scheme,
+    utils::color::{alpha_from_argb, blue_from_argb, green_from_argb, red_from_argb},
+};
+
+use super::{status_color, SUCCESS, WARNING};
+
+fn color(argb: u32) -> Color {
+    Color::from_rgba8(
+        red_from_argb(argb) as u8,
+        green_from_argb(argb) as u8,
+        blue_from_argb(argb) as u8,
+        alpha_from_argb(argb) as f32 / 255.0,
+    )
+}
+
+/**
+ * Background, text, primary and danger come straight from the scheme.
+ * Material has no success or warning roles, so those are a green and an amber
+ * harmonized towards primary.
+ */
+impl From<&Scheme> for Palette {
+    fn from(scheme: &Scheme) -> Self {
+        Palette {
+            background: color(scheme.background()),
+            text: color(scheme.on_background()),
+            primary: color(scheme.primary()),
+            success: color(status_color(scheme, SUCCESS)),
+            warning: color(status_color(scheme, WARNING)),
+            danger: color(scheme.error()),
+        }
+    }
+}
+
+/**
+ * A custom theme named "Material" whose extended palette iced generates from
+ * [`Palette::from`].
+ */
+impl From<&Scheme> for Theme {
+    fn from(scheme: &Scheme) -> Self {
+        Theme::custom(Cow::Borrowed("Material"), scheme.into())
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(any(feature = "egui", feature = "iced"))]
+use crate::{blend::harmonize, hct::HCT, scheme::Scheme};
+
+#[cfg(feature = "bevy")]
+pub mod bevy;
+#[cfg(feature = "crossterm")]
+pub mod crossterm;
+#[cfg(feature = "egui")]
+mod egui;
+#[cfg(feature = "iced")]
+mod iced;
+#[cfg(feature = "palette-interop")]
+mod palette;
+#[cfg(feature = "ratatui")]
+pub mod ratatui;
+
+/**
+ * Canonical hue for toolkit roles that signal success.
+ */
+#[cfg(feature = "iced")]
+const SUCCESS: u32 = 0xff4caf50;
+
+/**
+ * Canonical hue for toolkit roles that signal a warning.
+ */
+#[cfg(any(feature = "egui", feature = "iced"))]
+const WARNING: u32 = 0xffffb300;
+
+/**
+ * A status color for toolkits that expect one Material schemes lack: `argb`
+ * harmonized towards primary, at the tone of the scheme's error role so it
+ * reads the same way against the scheme's backgrounds.
+ */
+#[cfg(any(feature = "egui", feature = "iced"))]
+fn status_color(scheme: &Scheme, argb: u32) -> u32 {
+    let mut hct: HCT = harmonize(argb, scheme.primary()).into();
+    hct.set_tone(HCT::from(scheme.error()).tone());
+    hct.argb()
+}
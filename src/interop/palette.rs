@@ -0,0 +1,172 @@
+//! Conversions between this crate's colors and those of the `palette` crate,
+//! behind the `palette-interop` feature. Lab, LCh and OKLab colors convert
+//! with this crate's own matrices, so a color converted here matches the one
+//! [`crate::space`] would give.
+
+use ::palette::{
+    white_point::D65, LinSrgb, Oklab as PaletteOklab, Oklch as PaletteOklch, Srgb, Srgba,
+};
+
+use crate::{
+    hct::{cam16::Cam16, HCT},
+    space::{
+        lab::{Lab, Lch},
+        oklab::{OkLab, OkLch},
+        rgb::Rgb,
+        xyz::{LinearRgb, Xyz},
+    },
+    utils::color::argb_with_alpha,
+};
+
+type PaletteLab = ::palette::Lab<D65, f64>;
+type PaletteLch = ::palette::Lch<D65, f64>;
+type PaletteXyz = ::palette::Xyz<D65, f64>;
+
+impl From<Rgb<f64>> for Srgb<f64> {
+    fn from(rgb: Rgb<f64>) -> Self {
+        Srgb::new(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl From<Srgb<f64>> for Rgb<f64> {
+    fn from(srgb: Srgb<f64>) -> Self {
+        Rgb { r: srgb.red, g: srgb.green, b: srgb.blue }
+    }
+}
+
+impl From<Rgb<u8>> for Srgb<u8> {
+    fn from(rgb: Rgb<u8>) -> Self {
+        Srgb::new(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl From<Srgb<u8>> for Rgb<u8> {
+    fn from(srgb: Srgb<u8>) -> Self {
+        Rgb { r: srgb.red, g: srgb.green, b: srgb.blue }
+    }
+}
+
+/**
+ * `palette` keeps linear sRGB in 0..1, this crate in 0..100.
+ */
+impl From<LinearRgb> for LinSrgb<f64> {
+    fn from(linrgb: LinearRgb) -> Self {
+        LinSrgb::new(linrgb.r / 100.0, linrgb.g / 100.0, linrgb.b / 100.0)
+    }
+}
+
+impl From<LinSrgb<f64>> for LinearRgb {
+    fn from(linrgb: LinSrgb<f64>) -> Self {
+        LinearRgb::new(linrgb.red * 100.0, linrgb.green * 100.0, linrgb.blue * 100.0)
+    }
+}
+
+/**
+ * `palette` keeps XYZ with Y in 0..1, this crate in 0..100.
+ */
+impl From<Xyz> for PaletteXyz {
+    fn from(xyz: Xyz) -> Self {
+        PaletteXyz::new(xyz.x / 100.0, xyz.y / 100.0, xyz.z / 100.0)
+    }
+}
+
+impl From<PaletteXyz> for Xyz {
+    fn from(xyz: PaletteXyz) -> Self {
+        Xyz::new(xyz.x * 100.0, xyz.y * 100.0, xyz.z * 100.0)
+    }
+}
+
+impl From<Lab> for PaletteLab {
+    fn from(lab: Lab) -> Self {
+        PaletteLab::new(lab.l, lab.a, lab.b)
+    }
+}
+
+impl From<PaletteLab> for Lab {
+    fn from(lab: PaletteLab) -> Self {
+        Lab::new(lab.l, lab.a, lab.b)
+    }
+}
+
+impl From<Lch> for PaletteLch {
+    fn from(lch: Lch) -> Self {
+        PaletteLch::new(lch.l, lch.c, lch.h)
+    }
+}
+
+impl From<PaletteLch> for Lch {
+    fn from(lch: PaletteLch) -> Self {
+        Lch::new(lch.l, lch.chroma, lch.hue.into_positive_degrees())
+    }
+}
+
+impl From<OkLab> for PaletteOklab<f64> {
+    fn from(oklab: OkLab) -> Self {
+        PaletteOklab::new(oklab.l, oklab.a, oklab.b)
+    }
+}
+
+impl From<PaletteOklab<f64>> for OkLab {
+    fn from(oklab: PaletteOklab<f64>) -> Self {
+        OkLab::new(oklab.l, oklab.a, oklab.b)
+    }
+}
+
+impl From<OkLch> for PaletteOklch<f64> {
+    fn from(oklch: OkLch) -> Self {
+        PaletteOklch::new(oklch.l, oklch.c, oklch.h)
+    }
+}
+
+impl From<PaletteOklch<f64>> for OkLch {
+    fn from(oklch: PaletteOklch<f64>) -> Self {
+        OkLch::new(oklch.l, oklch.chroma, oklch.hue.into_positive_degrees())
+    }
+}
+
+/**
+ * Converts between `$color` and a `palette` type by way of one of this
+ * crate's types that both already convert to and from.
+ */
+macro_rules! convert_through {
+    ($color:ty, $through:ty, $theirs:ty) => {
+        impl From<$color> for $theirs {
+            fn from(color: $color) -> Self {
+                <$through>::from(color).into()
+            }
+        }
+
+        impl From<$theirs> for $color {
+            fn from(color: $theirs) -> Self {
+                <$through>::from(color).into()
+            }
+        }
+    };
+}
+
+convert_through!(HCT, Rgb<f64>, Srgb<f64>);
+convert_through!(HCT, Rgb<u8>, Srgb<u8>);
+convert_through!(HCT, LinearRgb, LinSrgb<f64>);
+convert_through!(HCT, Xyz, PaletteXyz);
+convert_through!(HCT, Lab, PaletteLab);
+convert_through!(HCT, Lch, PaletteLch);
+convert_through!(HCT, OkLab, PaletteOklab<f64>);
+convert_through!(HCT, OkLch, PaletteOklch<f64>);
+convert_through!(Cam16, Rgb<f64>, Srgb<f64>);
+
+/**
+ * Unlike the other conversions, keeps alpha.
+ */
+impl From<HCT> for Srgba<u8> {
+    fn from(hct: HCT) -> Self {
+        let Rgb { r, g, b } = Rgb::<u8>::from(hct.argb());
+        Srgba::new(r, g, b, hct.alpha())
+    }
+}
+
+impl From<Srgba<u8>> for HCT {
+    fn from(srgba: Srgba<u8>) -> Self {
+        let rgb: Rgb<u8> = srgba.color.into();
+        argb_with_alpha(rgb.into(), srgba.alpha as u32).into()
+    }
+}
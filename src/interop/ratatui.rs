@@ -0,0 +1,75 @@
+//! `ratatui` colors and styles from a [`Scheme`], behind the `ratatui`
+//! feature.
+
+use ::ratatui::style::{Color, Style};
+
+use crate::{
+    hct::HCT,
+    scheme::Scheme,
+    terminal::Ansi256,
+    utils::color::{blue_from_argb, green_from_argb, red_from_argb},
+};
+
+/**
+ * A true color [`Color::Rgb`]. Terminals have no alpha, so it is dropped.
+ */
+pub fn rgb(argb: u32) -> Color {
+    Color::Rgb(
+        red_from_argb(argb) as u8,
+        green_from_argb(argb) as u8,
+        blue_from_argb(argb) as u8,
+    )
+}
+
+impl From<HCT> for Color {
+    fn from(hct: HCT) -> Self {
+        rgb(hct.argb())
+    }
+}
+
+/**
+ * Every role of a scheme as a [`Color::Rgb`], named as in
+ * [`Scheme::roles`].
+ */
+pub fn roles(scheme: &Scheme) -> [(&'static str, Color); 27] {
+    scheme.roles().map(|(name, argb)| (name, rgb(argb)))
+}
+
+/**
+ * Ready-made styles for the main areas of a TUI. Surface is for the body,
+ * primary and error for highlighted and failing items, and outline for
+ * borders, drawn on surface.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SchemeStyles {
+    pub surface: Style,
+    pub primary: Style,
+    pub error: Style,
+    pub outline: Style,
+}
+
+impl SchemeStyles {
+    /**
+     * The same styles using [`Color::Indexed`], for terminals without true
+     * color. Every role is downsampled to its nearest color in `ansi`.
+     */
+    pub fn indexed(scheme: &Scheme, ansi: &Ansi256) -> Self {
+        Self::with(scheme, |argb| Color::Indexed(ansi.nearest(argb)))
+    }
+
+    fn with(scheme: &Scheme, color: impl Fn(u32) -> Color) -> Self {
+        let style = |fg, bg| Style::new().fg(color(fg)).bg(color(bg));
+        Self {
+            surface: style(scheme.on_surface(), scheme.surface()),
+            primary: style(scheme.on_primary(), scheme.primary()),
+            error: style(scheme.on_error(), scheme.error()),
+            outline: style(scheme.outline(), scheme.surface()),
+        }
+    }
+}
+
+impl From<&Scheme> for SchemeStyles {
+    fn from(scheme: &Scheme) -> Self {
+        Self::with(scheme, rgb)
+    }
+}
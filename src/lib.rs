@@ -1,13 +1,42 @@
-mod hct;
-mod blend;
-mod utils;
-mod palette;
-mod scheme;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("material-rs needs either the `std` or the `libm` feature for float math");
+
+extern crate alloc;
+
+/**
+ * Brings `utils::float::Float` into scope when building without `std`, so
+ * the same float method calls compile either way. When std ends up linked
+ * anyway, its inherent methods win and the import goes unused, hence the
+ * allowance.
+ */
+macro_rules! import_float {
+    () => {
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        use crate::utils::float::Float;
+    };
+}
+
+pub mod hct;
+pub mod blend;
+pub mod utils;
+pub mod palette;
+pub mod scheme;
+pub mod space;
+pub mod gradient;
+pub mod dataviz;
+pub mod contrast;
+pub mod cvd;
+pub mod terminal;
+pub mod base16;
+pub mod interop;
+
+mod accent;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn it_works() {}
 }
@@ -1,37 +1,270 @@
-use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
 
-use crate::hct::HCT;
+use crate::{hct::HCT, utils::color::argb_with_alpha};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+/**
+ * A hue and chroma at every tone. With the `std` feature, tones are computed
+ * on first use and cached behind a lock, so a palette can be shared between
+ * threads and looked up through a shared reference. Without it, every lookup
+ * solves the tone again.
+ *
+ * With the `serde` feature, serialized as its hue, chroma, key color and
+ * alpha. The cache is not serialized.
+ */
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "PaletteValue", into = "PaletteValue"))]
 pub struct TonalPalette {
-    cache: HashMap<u64, u32>,
+    cache: ToneCache,
     hue: f64,
     chroma: f64,
+    key_color: KeyColorCell,
+    alpha: u8,
 }
 
 impl TonalPalette {
-    fn new(hue: f64, chroma: f64) -> Self {
-        Self { cache: HashMap::new(), hue, chroma }
-    }
-
-    pub fn tone(&mut self, tone: f64) -> u32 {
-        match self.cache.get(&tone.to_bits()) {
-            Some(argb) => *argb,
-            None => {
-                let argb = HCT::new(self.hue, self.chroma, tone).argb();
-                self.cache.insert(tone.to_bits(), argb);
-                argb
-            },
+    fn new(hue: f64, chroma: f64, key_color: Option<HCT>) -> Self {
+        Self {
+            cache: ToneCache::new(),
+            hue,
+            chroma,
+            key_color: KeyColorCell::new(key_color),
+            alpha: 255,
         }
     }
+
+    /**
+     * A palette of the given hue and chroma, whose key color is the tone
+     * closest to 50 at which that chroma is actually reachable.
+     */
+    pub fn from_hue_and_chroma(hue: f64, chroma: f64) -> Self {
+        Self::new(hue, chroma, None)
+    }
+
+    pub fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    pub fn chroma(&self) -> f64 {
+        self.chroma
+    }
+
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    /**
+     * The color the palette was created from, or for palettes created from
+     * hue and chroma, the color found by the key color search. The search
+     * runs on the first call, and with the `std` feature its result is kept.
+     */
+    pub fn key_color(&self) -> HCT {
+        self.key_color.get_or_init(|| KeyColor::new(self.hue, self.chroma).create())
+    }
+
+    /**
+     * Tones share the alpha of the color the palette was created from.
+     */
+    pub fn tone(&self, tone: f64) -> u32 {
+        if let Some(argb) = self.cache.get(tone) {
+            return argb;
+        }
+        let argb = argb_with_alpha(HCT::new(self.hue, self.chroma, tone).argb(), self.alpha as u32);
+        self.cache.insert(tone, argb);
+        argb
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PaletteValue {
+    hue: f64,
+    chroma: f64,
+    key_color: HCT,
+    alpha: u8,
+}
+
+#[cfg(feature = "serde")]
+impl From<TonalPalette> for PaletteValue {
+    fn from(palette: TonalPalette) -> Self {
+        Self {
+            hue: palette.hue,
+            chroma: palette.chroma,
+            key_color: palette.key_color(),
+            alpha: palette.alpha,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<PaletteValue> for TonalPalette {
+    fn from(value: PaletteValue) -> Self {
+        Self {
+            alpha: value.alpha,
+            ..Self::new(value.hue, value.chroma, Some(value.key_color))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+struct ToneCache(RwLock<HashMap<u64, u32>>);
+
+#[cfg(feature = "std")]
+impl ToneCache {
+    fn new() -> Self {
+        Self(RwLock::new(HashMap::new()))
+    }
+
+    fn get(&self, tone: f64) -> Option<u32> {
+        self.0.read().ok()?.get(&tone.to_bits()).copied()
+    }
+
+    fn insert(&self, tone: f64, argb: u32) {
+        // A poisoned cache only holds finished entries, so it is still safe
+        // to add to.
+        let mut cache = self.0.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        cache.insert(tone.to_bits(), argb);
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clone for ToneCache {
+    fn clone(&self) -> Self {
+        let cache = match self.0.read() {
+            Ok(cache) => cache.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        Self(RwLock::new(cache))
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Clone)]
+struct ToneCache;
+
+#[cfg(not(feature = "std"))]
+impl ToneCache {
+    fn new() -> Self {
+        Self
+    }
+
+    fn get(&self, _tone: f64) -> Option<u32> {
+        None
+    }
+
+    fn insert(&self, _tone: f64, _argb: u32) {}
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct KeyColorCell(OnceLock<HCT>);
+
+#[cfg(feature = "std")]
+impl KeyColorCell {
+    fn new(key_color: Option<HCT>) -> Self {
+        let cell = OnceLock::new();
+        if let Some(key_color) = key_color {
+            let _ = cell.set(key_color);
+        }
+        Self(cell)
+    }
+
+    fn get_or_init(&self, search: impl FnOnce() -> HCT) -> HCT {
+        *self.0.get_or_init(search)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Clone)]
+struct KeyColorCell(Option<HCT>);
+
+#[cfg(not(feature = "std"))]
+impl KeyColorCell {
+    fn new(key_color: Option<HCT>) -> Self {
+        Self(key_color)
+    }
+
+    fn get_or_init(&self, search: impl FnOnce() -> HCT) -> HCT {
+        self.0.unwrap_or_else(search)
+    }
 }
 
 impl From<u32> for TonalPalette {
     fn from(argb: u32) -> Self {
         let hct: HCT = argb.into();
-        Self::new(hct.hue(), hct.chroma())
+        Self {
+            alpha: hct.alpha(),
+            ..Self::new(hct.hue(), hct.chroma(), Some(hct))
+        }
+    }
+}
+
+/**
+ * Finds the tone at which a hue reaches the requested chroma, preferring
+ * tones close to 50. If the chroma is not reachable at any tone, settles on
+ * the tone with the most chroma.
+ */
+struct KeyColor {
+    hue: f64,
+    requested_chroma: f64,
+    chroma_cache: [Option<f64>; 101],
+}
+
+impl KeyColor {
+    const MAX_CHROMA_VALUE: f64 = 200.0;
+
+    fn new(hue: f64, requested_chroma: f64) -> Self {
+        Self { hue, requested_chroma, chroma_cache: [None; 101] }
+    }
+
+    fn create(&mut self) -> HCT {
+        let pivot_tone = 50;
+        let tone_step_size = 1;
+        let epsilon = 0.01;
+
+        // Binary search for the tone that can reach the requested chroma,
+        // while staying as close to the pivot as possible.
+        let mut lower_tone = 0;
+        let mut upper_tone = 100;
+        while lower_tone < upper_tone {
+            let mid_tone = (lower_tone + upper_tone) / 2;
+            let is_ascending = self.max_chroma(mid_tone) < self.max_chroma(mid_tone + tone_step_size);
+            let sufficient_chroma = self.max_chroma(mid_tone) >= self.requested_chroma - epsilon;
+
+            if sufficient_chroma {
+                if (lower_tone - pivot_tone).abs() < (upper_tone - pivot_tone).abs() {
+                    upper_tone = mid_tone;
+                } else {
+                    if lower_tone == mid_tone {
+                        return HCT::new(self.hue, self.requested_chroma, lower_tone as f64);
+                    }
+                    lower_tone = mid_tone;
+                }
+            } else if is_ascending {
+                lower_tone = mid_tone + tone_step_size;
+            } else {
+                upper_tone = mid_tone;
+            }
+        }
+        HCT::new(self.hue, self.requested_chroma, lower_tone as f64)
+    }
+
+    fn max_chroma(&mut self, tone: i32) -> f64 {
+        let hue = self.hue;
+        *self.chroma_cache[tone as usize]
+            .get_or_insert_with(|| HCT::new(hue, Self::MAX_CHROMA_VALUE, tone as f64).chroma())
     }
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CorePalette {
     pub a1: TonalPalette,
     pub a2: TonalPalette,
@@ -57,21 +290,21 @@ impl CorePalette {
         let chroma = hct.chroma();
         if content {
             Self {
-                a1: TonalPalette::new(hue, chroma),
-                a2: TonalPalette::new(hue, chroma / 3.0),
-                a3: TonalPalette::new(hue + 60.0, chroma / 2.0),
-                n1: TonalPalette::new(hue, (chroma / 12.0).min(4.0)),
-                n2: TonalPalette::new(hue, (chroma / 6.0).min(8.0)),
-                error: TonalPalette::new(25.0, 84.0),
+                a1: TonalPalette::from_hue_and_chroma(hue, chroma),
+                a2: TonalPalette::from_hue_and_chroma(hue, chroma / 3.0),
+                a3: TonalPalette::from_hue_and_chroma(hue + 60.0, chroma / 2.0),
+                n1: TonalPalette::from_hue_and_chroma(hue, (chroma / 12.0).min(4.0)),
+                n2: TonalPalette::from_hue_and_chroma(hue, (chroma / 6.0).min(8.0)),
+                error: TonalPalette::from_hue_and_chroma(25.0, 84.0),
             }
         } else {
             Self {
-                a1: TonalPalette::new(hue, chroma.max(48.0)),
-                a2: TonalPalette::new(hue, 16.0),
-                a3: TonalPalette::new(hue + 60.0, 24.0),
-                n1: TonalPalette::new(hue, 4.0),
-                n2: TonalPalette::new(hue, 8.0),
-                error: TonalPalette::new(25.0, 84.0),
+                a1: TonalPalette::from_hue_and_chroma(hue, chroma.max(48.0)),
+                a2: TonalPalette::from_hue_and_chroma(hue, 16.0),
+                a3: TonalPalette::from_hue_and_chroma(hue + 60.0, 24.0),
+                n1: TonalPalette::from_hue_and_chroma(hue, 4.0),
+                n2: TonalPalette::from_hue_and_chroma(hue, 8.0),
+                error: TonalPalette::from_hue_and_chroma(25.0, 84.0),
             }
         }
     }
@@ -1,67 +1,70 @@
-use serde::Serialize;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
-use crate::palette::CorePalette;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
+use crate::{
+    blend::{state_layer, surface_tint, BlendSpace, State},
+    contrast::{
+        audit::AuditReport,
+        repair::{repair, Repair},
+    },
+    cvd::{simulate, Deficiency},
+    palette::CorePalette,
+    space::oklab::OkLch,
+};
+
+/**
+ * A color role of a [`Scheme`] as its name and color.
+ */
+pub type Role = (&'static str, u32);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Scheme {
     primary: u32,
-    #[serde(rename = "onPrimary")]
     on_primary: u32,
-    #[serde(rename = "primaryContainer")]
     primary_container: u32,
-    #[serde(rename = "onPrimaryContainer")]
     on_primary_container: u32,
     secondary: u32,
-    #[serde(rename = "onSecondary")]
     on_secondary: u32,
-    #[serde(rename = "secondaryContainer")]
     secondary_container: u32,
-    #[serde(rename = "onSecondaryContainer")]
     on_secondary_container: u32,
     tertiary: u32,
-    #[serde(rename = "onTertiary")]
     on_tertiary: u32,
-    #[serde(rename = "tertiaryContainer")]
     tertiary_container: u32,
-    #[serde(rename = "onTertiaryContainer")]
     on_tertiary_container: u32,
     error: u32,
-    #[serde(rename = "onError")]
     on_error: u32,
-    #[serde(rename = "errorContainer")]
     error_container: u32,
-    #[serde(rename = "onErrorContainer")]
     on_error_container: u32,
     background: u32,
-    #[serde(rename = "onBackground")]
     on_background: u32,
     surface: u32,
-    #[serde(rename = "onSurface")]
     on_surface: u32,
-    #[serde(rename = "surfaceVariant")]
     surface_variant: u32,
-    #[serde(rename = "onSurfaceVariant")]
     on_surface_variant: u32,
     outline: u32,
     shadow: u32,
-    #[serde(rename = "inverseSurface")]
     inverse_surface: u32,
-    #[serde(rename = "inverseOnSurface")]
     inverse_on_surface: u32,
-    #[serde(rename = "inversePrimary")]
     inverse_primary: u32
 }
 
 impl Scheme {
     pub fn light(argb: u32) -> Self {
-        Self::light_from_core_palette(&mut CorePalette::of(argb))
+        Self::light_from_core_palette(&CorePalette::of(argb))
     }
 
     pub fn light_content(argb: u32) -> Self {
-        Self::light_from_core_palette(&mut CorePalette::content_of(argb))
+        Self::light_from_core_palette(&CorePalette::content_of(argb))
     }
 
-    pub fn light_from_core_palette(core: &mut CorePalette) -> Self {
+    pub fn light_from_core_palette(core: &CorePalette) -> Self {
         Self {
             primary:                core.a1.tone(40.0),
             on_primary:             core.a1.tone(100.0),
@@ -94,14 +97,14 @@ impl Scheme {
     }
 
     pub fn dark(argb: u32) -> Self {
-        Self::dark_from_core_palette(&mut CorePalette::of(argb))
+        Self::dark_from_core_palette(&CorePalette::of(argb))
     }
 
     pub fn dark_content(argb: u32) -> Self {
-        Self::dark_from_core_palette(&mut CorePalette::content_of(argb))
+        Self::dark_from_core_palette(&CorePalette::content_of(argb))
     }
 
-    pub fn dark_from_core_palette(core: &mut CorePalette) -> Self {
+    pub fn dark_from_core_palette(core: &CorePalette) -> Self {
         Self {
             primary:                core.a1.tone(80.0),
             on_primary:             core.a1.tone(20.0),
@@ -133,7 +136,322 @@ impl Scheme {
         }
     }
 
-    pub fn json(&self) -> Result<std::string::String, serde_json::Error> {
+    /**
+     * Every color role paired with its name, in declaration order. Names match
+     * the keys produced by [`Scheme::json`].
+     */
+    pub fn roles(&self) -> [Role; 27] {
+        [
+            ("primary", self.primary),
+            ("onPrimary", self.on_primary),
+            ("primaryContainer", self.primary_container),
+            ("onPrimaryContainer", self.on_primary_container),
+            ("secondary", self.secondary),
+            ("onSecondary", self.on_secondary),
+            ("secondaryContainer", self.secondary_container),
+            ("onSecondaryContainer", self.on_secondary_container),
+            ("tertiary", self.tertiary),
+            ("onTertiary", self.on_tertiary),
+            ("tertiaryContainer", self.tertiary_container),
+            ("onTertiaryContainer", self.on_tertiary_container),
+            ("error", self.error),
+            ("onError", self.on_error),
+            ("errorContainer", self.error_container),
+            ("onErrorContainer", self.on_error_container),
+            ("background", self.background),
+            ("onBackground", self.on_background),
+            ("surface", self.surface),
+            ("onSurface", self.on_surface),
+            ("surfaceVariant", self.surface_variant),
+            ("onSurfaceVariant", self.on_surface_variant),
+            ("outline", self.outline),
+            ("shadow", self.shadow),
+            ("inverseSurface", self.inverse_surface),
+            ("inverseOnSurface", self.inverse_on_surface),
+            ("inversePrimary", self.inverse_primary),
+        ]
+    }
+
+    pub fn primary(&self) -> u32 {
+        self.primary
+    }
+
+    pub fn on_primary(&self) -> u32 {
+        self.on_primary
+    }
+
+    pub fn primary_container(&self) -> u32 {
+        self.primary_container
+    }
+
+    pub fn on_primary_container(&self) -> u32 {
+        self.on_primary_container
+    }
+
+    pub fn secondary(&self) -> u32 {
+        self.secondary
+    }
+
+    pub fn on_secondary(&self) -> u32 {
+        self.on_secondary
+    }
+
+    pub fn secondary_container(&self) -> u32 {
+        self.secondary_container
+    }
+
+    pub fn on_secondary_container(&self) -> u32 {
+        self.on_secondary_container
+    }
+
+    pub fn tertiary(&self) -> u32 {
+        self.tertiary
+    }
+
+    pub fn on_tertiary(&self) -> u32 {
+        self.on_tertiary
+    }
+
+    pub fn tertiary_container(&self) -> u32 {
+        self.tertiary_container
+    }
+
+    pub fn on_tertiary_container(&self) -> u32 {
+        self.on_tertiary_container
+    }
+
+    pub fn error(&self) -> u32 {
+        self.error
+    }
+
+    pub fn on_error(&self) -> u32 {
+        self.on_error
+    }
+
+    pub fn error_container(&self) -> u32 {
+        self.error_container
+    }
+
+    pub fn on_error_container(&self) -> u32 {
+        self.on_error_container
+    }
+
+    pub fn background(&self) -> u32 {
+        self.background
+    }
+
+    pub fn on_background(&self) -> u32 {
+        self.on_background
+    }
+
+    pub fn surface(&self) -> u32 {
+        self.surface
+    }
+
+    pub fn on_surface(&self) -> u32 {
+        self.on_surface
+    }
+
+    pub fn surface_variant(&self) -> u32 {
+        self.surface_variant
+    }
+
+    pub fn on_surface_variant(&self) -> u32 {
+        self.on_surface_variant
+    }
+
+    pub fn outline(&self) -> u32 {
+        self.outline
+    }
+
+    pub fn shadow(&self) -> u32 {
+        self.shadow
+    }
+
+    pub fn inverse_surface(&self) -> u32 {
+        self.inverse_surface
+    }
+
+    pub fn inverse_on_surface(&self) -> u32 {
+        self.inverse_on_surface
+    }
+
+    pub fn inverse_primary(&self) -> u32 {
+        self.inverse_primary
+    }
+
+    /**
+     * The color of the role called `name`, using the names of
+     * [`Scheme::roles`].
+     */
+    pub fn role(&self, name: &str) -> Option<u32> {
+        self.roles()
+            .into_iter()
+            .find(|(role, _)| *role == name)
+            .map(|(_, argb)| argb)
+    }
+
+    /**
+     * Overrides the color of the role called `name`. Returns `false` if there
+     * is no such role.
+     */
+    pub fn set_role(&mut self, name: &str, argb: u32) -> bool {
+        match self.role_mut(name) {
+            Some(role) => {
+                *role = argb;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn role_mut(&mut self, name: &str) -> Option<&mut u32> {
+        match name {
+            "primary" => Some(&mut self.primary),
+            "onPrimary" => Some(&mut self.on_primary),
+            "primaryContainer" => Some(&mut self.primary_container),
+            "onPrimaryContainer" => Some(&mut self.on_primary_container),
+            "secondary" => Some(&mut self.secondary),
+            "onSecondary" => Some(&mut self.on_secondary),
+            "secondaryContainer" => Some(&mut self.secondary_container),
+            "onSecondaryContainer" => Some(&mut self.on_secondary_container),
+            "tertiary" => Some(&mut self.tertiary),
+            "onTertiary" => Some(&mut self.on_tertiary),
+            "tertiaryContainer" => Some(&mut self.tertiary_container),
+            "onTertiaryContainer" => Some(&mut self.on_tertiary_container),
+            "error" => Some(&mut self.error),
+            "onError" => Some(&mut self.on_error),
+            "errorContainer" => Some(&mut self.error_container),
+            "onErrorContainer" => Some(&mut self.on_error_container),
+            "background" => Some(&mut self.background),
+            "onBackground" => Some(&mut self.on_background),
+            "surface" => Some(&mut self.surface),
+            "onSurface" => Some(&mut self.on_surface),
+            "surfaceVariant" => Some(&mut self.surface_variant),
+            "onSurfaceVariant" => Some(&mut self.on_surface_variant),
+            "outline" => Some(&mut self.outline),
+            "shadow" => Some(&mut self.shadow),
+            "inverseSurface" => Some(&mut self.inverse_surface),
+            "inverseOnSurface" => Some(&mut self.inverse_on_surface),
+            "inversePrimary" => Some(&mut self.inverse_primary),
+            _ => None,
+        }
+    }
+
+    /**
+     * Every container role paired with the content role drawn on top of it,
+     * as `(container, content)`.
+     */
+    pub fn pairs(&self) -> [(Role, Role); 12] {
+        [
+            (("primary", self.primary), ("onPrimary", self.on_primary)),
+            (("primaryContainer", self.primary_container), ("onPrimaryContainer", self.on_primary_container)),
+            (("secondary", self.secondary), ("onSecondary", self.on_secondary)),
+            (("secondaryContainer", self.secondary_container), ("onSecondaryContainer", self.on_secondary_container)),
+            (("tertiary", self.tertiary), ("onTertiary", self.on_tertiary)),
+            (("tertiaryContainer", self.tertiary_container), ("onTertiaryContainer", self.on_tertiary_container)),
+            (("error", self.error), ("onError", self.on_error)),
+            (("errorContainer", self.error_container), ("onErrorContainer", self.on_error_container)),
+            (("background", self.background), ("onBackground", self.on_background)),
+            (("surface", self.surface), ("onSurface", self.on_surface)),
+            (("surfaceVariant", self.surface_variant), ("onSurfaceVariant", self.on_surface_variant)),
+            (("inverseSurface", self.inverse_surface), ("inverseOnSurface", self.inverse_on_surface)),
+        ]
+    }
+
+    /**
+     * Precomputed state layer colors for every role. Container roles get
+     * their content role drawn over them, any other role is treated as
+     * content drawn over `surface`.
+     */
+    pub fn state_colors(&self, space: BlendSpace) -> Vec<(&'static str, StateColors)> {
+        let pairs = self.pairs();
+        self.roles()
+            .into_iter()
+            .map(|(name, argb)| {
+                let (content, container) = pairs
+                    .iter()
+                    .find(|((container, _), _)| *container == name)
+                    .map(|(_, (_, content))| (*content, argb))
+                    .unwrap_or((argb, self.surface));
+                (name, StateColors::new(content, container, space))
+            })
+            .collect()
+    }
+
+    /**
+     * The surface color at elevation `level` (0 through 5), tinted with
+     * primary.
+     */
+    pub fn surface_at_elevation(&self, level: usize) -> u32 {
+        surface_tint(self.surface, self.primary, level)
+    }
+
+    /**
+     * Checks the contrast of every content/container pair, see
+     * [`AuditReport`].
+     */
+    pub fn audit(&self) -> AuditReport {
+        AuditReport::of(self)
+    }
+
+    /**
+     * Restores a contrast ratio of at least `ratio` between every
+     * content/container pair by adjusting content tones, see
+     * [`repair`](crate::contrast::repair::repair).
+     */
+    pub fn repair(&mut self, ratio: f64) -> Vec<Repair> {
+        repair(self, ratio)
+    }
+
+    /**
+     * The scheme as it appears with the given color vision deficiency.
+     */
+    pub fn simulate(&self, deficiency: Deficiency) -> Self {
+        let mut scheme = self.clone();
+        for (name, argb) in self.roles() {
+            scheme.set_role(name, simulate(argb, deficiency));
+        }
+        scheme
+    }
+
+    /**
+     * Every color role formatted as a CSS `oklch()` color.
+     */
+    pub fn oklch(&self) -> Vec<(&'static str, String)> {
+        self.roles()
+            .into_iter()
+            .map(|(name, argb)| (name, OkLch::from(argb).to_string()))
+            .collect()
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
     }
-}
\ No newline at end of file
+}
+
+/**
+ * A container color with each interaction state's layer composited over it.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StateColors {
+    pub enabled: u32,
+    pub hover: u32,
+    pub focus: u32,
+    pub pressed: u32,
+    pub dragged: u32,
+}
+
+impl StateColors {
+    pub fn new(content: u32, container: u32, space: BlendSpace) -> Self {
+        Self {
+            enabled: container,
+            hover: state_layer(content, container, State::Hover, space),
+            focus: state_layer(content, container, State::Focus, space),
+            pressed: state_layer(content, container, State::Pressed, space),
+            dragged: state_layer(content, container, State::Dragged, space),
+        }
+    }
+}
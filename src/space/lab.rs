@@ -0,0 +1,130 @@
+use crate::{
+    hct::HCT,
+    utils::{
+        color::{argb_from_lab, lab_from_argb, lab_from_xyz, xyz_from_lab},
+        math::sanitize_degrees_double,
+    },
+};
+
+use super::xyz::Xyz;
+import_float!();
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+ * CIELAB under the D65 white point. L* ranges from 0 to 100, a* and b* are
+ * unbounded but stay roughly within -128..128 for colors inside sRGB.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Lab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl From<u32> for Lab {
+    fn from(argb: u32) -> Self {
+        let [l, a, b] = lab_from_argb(argb);
+        Self { l, a, b }
+    }
+}
+
+impl From<Lab> for u32 {
+    fn from(lab: Lab) -> Self {
+        argb_from_lab(lab.l, lab.a, lab.b)
+    }
+}
+
+impl From<Xyz> for Lab {
+    fn from(xyz: Xyz) -> Self {
+        let [l, a, b] = lab_from_xyz(xyz.x, xyz.y, xyz.z);
+        Self { l, a, b }
+    }
+}
+
+impl From<Lab> for Xyz {
+    fn from(lab: Lab) -> Self {
+        let [x, y, z] = xyz_from_lab(lab.l, lab.a, lab.b);
+        Xyz::new(x, y, z)
+    }
+}
+
+impl From<HCT> for Lab {
+    fn from(hct: HCT) -> Self {
+        hct.argb().into()
+    }
+}
+
+impl From<Lab> for HCT {
+    fn from(lab: Lab) -> Self {
+        u32::from(lab).into()
+    }
+}
+
+/**
+ * The cylindrical form of CIELAB. Lightness is shared with [`Lab`], chroma is
+ * the distance from the neutral axis and hue is given in degrees.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Lch {
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        let c = (lab.a * lab.a + lab.b * lab.b).sqrt();
+        let h = sanitize_degrees_double(lab.b.atan2(lab.a).to_degrees());
+        Self { l: lab.l, c, h }
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        let h = lch.h.to_radians();
+        Self {
+            l: lch.l,
+            a: lch.c * h.cos(),
+            b: lch.c * h.sin(),
+        }
+    }
+}
+
+impl From<u32> for Lch {
+    fn from(argb: u32) -> Self {
+        Lab::from(argb).into()
+    }
+}
+
+impl From<Lch> for u32 {
+    fn from(lch: Lch) -> Self {
+        Lab::from(lch).into()
+    }
+}
+
+impl From<HCT> for Lch {
+    fn from(hct: HCT) -> Self {
+        hct.argb().into()
+    }
+}
+
+impl From<Lch> for HCT {
+    fn from(lch: Lch) -> Self {
+        u32::from(lch).into()
+    }
+}
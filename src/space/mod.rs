@@ -0,0 +1,4 @@
+pub mod lab;
+pub mod oklab;
+pub mod rgb;
+pub mod xyz;
@@ -0,0 +1,169 @@
+use core::fmt;
+
+use crate::{
+    hct::HCT,
+    utils::math::{matrix_multiply, sanitize_degrees_double},
+};
+
+use super::xyz::LinearRgb;
+import_float!();
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const LMS_FROM_LINRGB: [[f64; 3]; 3] = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+
+const OKLAB_FROM_LMS: [[f64; 3]; 3] = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+const LMS_FROM_OKLAB: [[f64; 3]; 3] = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+const LINRGB_FROM_LMS: [[f64; 3]; 3] = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+/**
+ * OKLab, Björn Ottosson's perceptual color space as used by CSS Color 4.
+ * L ranges from 0 to 1, a and b stay roughly within -0.4..0.4.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OkLab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl OkLab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl From<LinearRgb> for OkLab {
+    fn from(linrgb: LinearRgb) -> Self {
+        let lms = matrix_multiply(
+            [linrgb.r / 100.0, linrgb.g / 100.0, linrgb.b / 100.0],
+            LMS_FROM_LINRGB,
+        );
+        let [l, a, b] = matrix_multiply(
+            [lms[0].cbrt(), lms[1].cbrt(), lms[2].cbrt()],
+            OKLAB_FROM_LMS,
+        );
+        Self { l, a, b }
+    }
+}
+
+impl From<OkLab> for LinearRgb {
+    fn from(oklab: OkLab) -> Self {
+        let lms = matrix_multiply([oklab.l, oklab.a, oklab.b], LMS_FROM_OKLAB);
+        let [r, g, b] = matrix_multiply(
+            [lms[0].powi(3), lms[1].powi(3), lms[2].powi(3)],
+            LINRGB_FROM_LMS,
+        );
+        LinearRgb::new(r * 100.0, g * 100.0, b * 100.0)
+    }
+}
+
+impl From<u32> for OkLab {
+    fn from(argb: u32) -> Self {
+        LinearRgb::from(argb).into()
+    }
+}
+
+impl From<OkLab> for u32 {
+    fn from(oklab: OkLab) -> Self {
+        LinearRgb::from(oklab).into()
+    }
+}
+
+impl From<HCT> for OkLab {
+    fn from(hct: HCT) -> Self {
+        hct.argb().into()
+    }
+}
+
+impl From<OkLab> for HCT {
+    fn from(oklab: OkLab) -> Self {
+        u32::from(oklab).into()
+    }
+}
+
+/**
+ * The cylindrical form of [`OkLab`], with hue in degrees. Formats as a CSS
+ * `oklch()` color.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OkLch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl OkLch {
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+}
+
+impl From<OkLab> for OkLch {
+    fn from(oklab: OkLab) -> Self {
+        let c = (oklab.a * oklab.a + oklab.b * oklab.b).sqrt();
+        let h = sanitize_degrees_double(oklab.b.atan2(oklab.a).to_degrees());
+        Self { l: oklab.l, c, h }
+    }
+}
+
+impl From<OkLch> for OkLab {
+    fn from(oklch: OkLch) -> Self {
+        let h = oklch.h.to_radians();
+        Self {
+            l: oklch.l,
+            a: oklch.c * h.cos(),
+            b: oklch.c * h.sin(),
+        }
+    }
+}
+
+impl From<u32> for OkLch {
+    fn from(argb: u32) -> Self {
+        OkLab::from(argb).into()
+    }
+}
+
+impl From<OkLch> for u32 {
+    fn from(oklch: OkLch) -> Self {
+        OkLab::from(oklch).into()
+    }
+}
+
+impl From<HCT> for OkLch {
+    fn from(hct: HCT) -> Self {
+        hct.argb().into()
+    }
+}
+
+impl From<OkLch> for HCT {
+    fn from(oklch: OkLch) -> Self {
+        u32::from(oklch).into()
+    }
+}
+
+impl fmt::Display for OkLch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oklch({:.2}% {:.4} {:.2})", self.l * 100.0, self.c, self.h)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    hct::{
+        gamut::{Gamut, GamutRgb},
+        HCT,
+    },
+    utils::color::{argb_from_rgb, blue_from_argb, green_from_argb, red_from_argb},
+};
+
+use super::xyz::LinearRgb;
+import_float!();
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+ * Gamma encoded sRGB. `Rgb<u8>` holds the usual 8-bit channels, while
+ * `Rgb<f64>` holds unquantized channels in 0..1 and is only rounded when
+ * converted back into ARGB.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rgb<T> {
+    pub r: T,
+    pub g: T,
+    pub b: T,
+}
+
+impl<T> Rgb<T> {
+    pub fn new(r: T, g: T, b: T) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl From<u32> for Rgb<u8> {
+    fn from(argb: u32) -> Self {
+        Self {
+            r: red_from_argb(argb) as u8,
+            g: green_from_argb(argb) as u8,
+            b: blue_from_argb(argb) as u8,
+        }
+    }
+}
+
+impl From<Rgb<u8>> for u32 {
+    fn from(rgb: Rgb<u8>) -> Self {
+        argb_from_rgb(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl From<Rgb<u8>> for Rgb<f64> {
+    fn from(rgb: Rgb<u8>) -> Self {
+        Self {
+            r: rgb.r as f64 / 255.0,
+            g: rgb.g as f64 / 255.0,
+            b: rgb.b as f64 / 255.0,
+        }
+    }
+}
+
+impl From<Rgb<f64>> for Rgb<u8> {
+    fn from(rgb: Rgb<f64>) -> Self {
+        let quantize = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self {
+            r: quantize(rgb.r),
+            g: quantize(rgb.g),
+            b: quantize(rgb.b),
+        }
+    }
+}
+
+impl From<u32> for Rgb<f64> {
+    fn from(argb: u32) -> Self {
+        Rgb::<u8>::from(argb).into()
+    }
+}
+
+impl From<Rgb<f64>> for u32 {
+    fn from(rgb: Rgb<f64>) -> Self {
+        Rgb::<u8>::from(rgb).into()
+    }
+}
+
+impl From<Rgb<f64>> for LinearRgb {
+    fn from(rgb: Rgb<f64>) -> Self {
+        LinearRgb::new(
+            Gamut::Srgb.linearized(rgb.r),
+            Gamut::Srgb.linearized(rgb.g),
+            Gamut::Srgb.linearized(rgb.b),
+        )
+    }
+}
+
+impl From<LinearRgb> for Rgb<f64> {
+    fn from(linrgb: LinearRgb) -> Self {
+        Self {
+            r: Gamut::Srgb.delinearized(linrgb.r),
+            g: Gamut::Srgb.delinearized(linrgb.g),
+            b: Gamut::Srgb.delinearized(linrgb.b),
+        }
+    }
+}
+
+impl From<GamutRgb> for Rgb<f64> {
+    fn from(rgb: GamutRgb) -> Self {
+        let GamutRgb { r, g, b, .. } = rgb.to_gamut(Gamut::Srgb);
+        Self { r, g, b }
+    }
+}
+
+impl From<HCT> for Rgb<u8> {
+    fn from(hct: HCT) -> Self {
+        hct.argb().into()
+    }
+}
+
+impl From<Rgb<u8>> for HCT {
+    fn from(rgb: Rgb<u8>) -> Self {
+        u32::from(rgb).into()
+    }
+}
+
+impl From<HCT> for Rgb<f64> {
+    fn from(hct: HCT) -> Self {
+        hct.rgb()
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    hct::HCT,
+    utils::color::{
+        argb_from_linrgb, argb_from_xyz, blue_from_argb, green_from_argb, linearized,
+        linrgb_from_xyz, red_from_argb, xyz_from_argb, xyz_from_linrgb,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+ * CIE 1931 XYZ under the D65 white point, scaled so that white has a Y of 100.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Xyz {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Xyz {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<u32> for Xyz {
+    fn from(argb: u32) -> Self {
+        let [x, y, z] = xyz_from_argb(argb);
+        Self { x, y, z }
+    }
+}
+
+impl From<Xyz> for u32 {
+    fn from(xyz: Xyz) -> Self {
+        argb_from_xyz(xyz.x, xyz.y, xyz.z)
+    }
+}
+
+impl From<LinearRgb> for Xyz {
+    fn from(linrgb: LinearRgb) -> Self {
+        let [x, y, z] = xyz_from_linrgb([linrgb.r, linrgb.g, linrgb.b]);
+        Self { x, y, z }
+    }
+}
+
+impl From<HCT> for Xyz {
+    fn from(hct: HCT) -> Self {
+        hct.argb().into()
+    }
+}
+
+impl From<Xyz> for HCT {
+    fn from(xyz: Xyz) -> Self {
+        u32::from(xyz).into()
+    }
+}
+
+/**
+ * Linear sRGB, i.e. sRGB with the transfer function removed. Like the rest of
+ * the crate, channels are scaled to 0..100 rather than 0..1.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl LinearRgb {
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl From<u32> for LinearRgb {
+    fn from(argb: u32) -> Self {
+        Self {
+            r: linearized(red_from_argb(argb)),
+            g: linearized(green_from_argb(argb)),
+            b: linearized(blue_from_argb(argb)),
+        }
+    }
+}
+
+impl From<LinearRgb> for u32 {
+    fn from(linrgb: LinearRgb) -> Self {
+        argb_from_linrgb([linrgb.r, linrgb.g, linrgb.b])
+    }
+}
+
+impl From<Xyz> for LinearRgb {
+    fn from(xyz: Xyz) -> Self {
+        let [r, g, b] = linrgb_from_xyz(xyz.x, xyz.y, xyz.z);
+        Self { r, g, b }
+    }
+}
+
+impl From<HCT> for LinearRgb {
+    fn from(hct: HCT) -> Self {
+        hct.argb().into()
+    }
+}
+
+impl From<LinearRgb> for HCT {
+    fn from(linrgb: LinearRgb) -> Self {
+        u32::from(linrgb).into()
+    }
+}
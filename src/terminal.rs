@@ -0,0 +1,348 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+use crate::{
+    accent::{harmonized_accent, ACCENT_CHROMA, BLUE, CYAN, GREEN, MAGENTA, RED, YELLOW},
+    hct::cam16::Cam16,
+    palette::CorePalette,
+    space::lab::Lab,
+    utils::{
+        math::lerp,
+        string::{escape_double_quoted, hex_from_argb},
+    },
+};
+
+/**
+ * The ANSI accents, in ANSI order.
+ */
+const CANONICAL: [u32; 6] = [RED, GREEN, YELLOW, BLUE, MAGENTA, CYAN];
+
+const NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/**
+ * Tones each terminal color is placed at, per theme brightness.
+ */
+struct Tones {
+    background: f64,
+    foreground: f64,
+    black: f64,
+    bright_black: f64,
+    white: f64,
+    bright_white: f64,
+    normal: f64,
+    bright: f64,
+    cursor: f64,
+    selection: f64,
+}
+
+const DARK: Tones = Tones {
+    background: 6.0,
+    foreground: 90.0,
+    black: 20.0,
+    bright_black: 50.0,
+    white: 80.0,
+    bright_white: 95.0,
+    normal: 70.0,
+    bright: 80.0,
+    cursor: 80.0,
+    selection: 30.0,
+};
+
+const LIGHT: Tones = Tones {
+    background: 98.0,
+    foreground: 10.0,
+    black: 10.0,
+    bright_black: 40.0,
+    white: 80.0,
+    bright_white: 95.0,
+    normal: 40.0,
+    bright: 50.0,
+    cursor: 40.0,
+    selection: 90.0,
+};
+
+/**
+ * A terminal color scheme: the 16 ANSI colors plus the colors terminals
+ * draw text, the cursor and the selection with. Black and white come from
+ * the seed's neutral palettes. The other ANSI colors keep their usual hues,
+ * placed at a fixed tone and chroma and harmonized towards the seed.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TerminalColors {
+    pub background: u32,
+    pub foreground: u32,
+    pub cursor: u32,
+    pub cursor_text: u32,
+    pub selection_background: u32,
+    pub selection_foreground: u32,
+    /// Black through white, then bright black through bright white.
+    pub ansi: [u32; 16],
+}
+
+impl TerminalColors {
+    pub fn dark(seed: u32) -> Self {
+        Self::new(seed, &DARK)
+    }
+
+    pub fn light(seed: u32) -> Self {
+        Self::new(seed, &LIGHT)
+    }
+
+    fn new(seed: u32, tones: &Tones) -> Self {
+        let core = CorePalette::of(seed);
+        let mut ansi = [0; 16];
+        ansi[0] = core.n1.tone(tones.black);
+        ansi[7] = core.n1.tone(tones.white);
+        ansi[8] = core.n2.tone(tones.bright_black);
+        ansi[15] = core.n1.tone(tones.bright_white);
+        for (i, canonical) in CANONICAL.into_iter().enumerate() {
+            ansi[i + 1] = harmonized_accent(canonical, ACCENT_CHROMA, tones.normal, seed);
+            ansi[i + 9] = harmonized_accent(canonical, ACCENT_CHROMA, tones.bright, seed);
+        }
+        let background = core.n1.tone(tones.background);
+        let foreground = core.n1.tone(tones.foreground);
+        Self {
+            background,
+            foreground,
+            cursor: core.a1.tone(tones.cursor),
+            cursor_text: background,
+            selection_background: core.a2.tone(tones.selection),
+            selection_foreground: foreground,
+            ansi,
+        }
+    }
+
+    /**
+     * The full 256-color palette. The first 16 are [`TerminalColors::ansi`].
+     * The 6×6×6 cube is interpolated in CIELAB between the background, the
+     * six ANSI colors and the foreground at its corners, and the 24 step gray
+     * ramp between the background and the foreground, so every index follows
+     * the theme.
+     */
+    pub fn ansi_256(&self) -> [u32; 256] {
+        let mut colors = [0; 256];
+        colors[..16].copy_from_slice(&self.ansi);
+
+        // ANSI numbers red as 1, green as 2 and blue as 4, so a corner's
+        // index is also the ANSI color at that corner.
+        let corners: [Lab; 8] = core::array::from_fn(|i| match i {
+            0 => self.background.into(),
+            7 => self.foreground.into(),
+            _ => self.ansi[i].into(),
+        });
+        for r in 0..6 {
+            for g in 0..6 {
+                for b in 0..6 {
+                    let (tr, tg, tb) = (r as f64 / 5.0, g as f64 / 5.0, b as f64 / 5.0);
+                    let c0 = lerp_lab(corners[0], corners[1], tr);
+                    let c1 = lerp_lab(corners[2], corners[3], tr);
+                    let c2 = lerp_lab(corners[4], corners[5], tr);
+                    let c3 = lerp_lab(corners[6], corners[7], tr);
+                    let lab = lerp_lab(lerp_lab(c0, c1, tg), lerp_lab(c2, c3, tg), tb);
+                    colors[16 + 36 * r + 6 * g + b] = lab.into();
+                }
+            }
+        }
+
+        for i in 0..24 {
+            let t = (i + 1) as f64 / 25.0;
+            colors[232 + i] = lerp_lab(corners[0], corners[7], t).into();
+        }
+        colors
+    }
+
+    /**
+     * Alacritty's TOML color configuration.
+     */
+    pub fn alacritty(&self) -> String {
+        let mut toml = String::new();
+        let _ = writeln!(toml, "[colors.primary]");
+        let _ = writeln!(toml, "background = \"{}\"", hex_from_argb(self.background));
+        let _ = writeln!(toml, "foreground = \"{}\"", hex_from_argb(self.foreground));
+        let _ = writeln!(toml, "\n[colors.cursor]");
+        let _ = writeln!(toml, "cursor = \"{}\"", hex_from_argb(self.cursor));
+        let _ = writeln!(toml, "text = \"{}\"", hex_from_argb(self.cursor_text));
+        let _ = writeln!(toml, "\n[colors.selection]");
+        let _ = writeln!(toml, "background = \"{}\"", hex_from_argb(self.selection_background));
+        let _ = writeln!(toml, "text = \"{}\"", hex_from_argb(self.selection_foreground));
+        for (table, colors) in [("normal", &self.ansi[..8]), ("bright", &self.ansi[8..])] {
+            let _ = writeln!(toml, "\n[colors.{table}]");
+            for (name, argb) in NAMES.iter().zip(colors) {
+                let _ = writeln!(toml, "{name} = \"{}\"", hex_from_argb(*argb));
+            }
+        }
+        toml
+    }
+
+    /**
+     * A kitty color configuration, to `include` from `kitty.conf`.
+     */
+    pub fn kitty(&self) -> String {
+        let mut conf = String::new();
+        let _ = writeln!(conf, "foreground {}", hex_from_argb(self.foreground));
+        let _ = writeln!(conf, "background {}", hex_from_argb(self.background));
+        let _ = writeln!(conf, "selection_foreground {}", hex_from_argb(self.selection_foreground));
+        let _ = writeln!(conf, "selection_background {}", hex_from_argb(self.selection_background));
+        let _ = writeln!(conf, "cursor {}", hex_from_argb(self.cursor));
+        let _ = writeln!(conf, "cursor_text_color {}", hex_from_argb(self.cursor_text));
+        for (i, argb) in self.ansi.iter().enumerate() {
+            let _ = writeln!(conf, "color{i} {}", hex_from_argb(*argb));
+        }
+        conf
+    }
+
+    /**
+     * A WezTerm color scheme as a Lua table, to return from a module and
+     * assign to `config.colors`.
+     */
+    pub fn wezterm(&self) -> String {
+        let list = |colors: &[u32]| {
+            colors
+                .iter()
+                .map(|argb| format!("\"{}\"", hex_from_argb(*argb)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut lua = String::from("return {\n");
+        let _ = writeln!(lua, "  foreground = \"{}\",", hex_from_argb(self.foreground));
+        let _ = writeln!(lua, "  background = \"{}\",", hex_from_argb(self.background));
+        let _ = writeln!(lua, "  cursor_bg = \"{}\",", hex_from_argb(self.cursor));
+        let _ = writeln!(lua, "  cursor_fg = \"{}\",", hex_from_argb(self.cursor_text));
+        let _ = writeln!(lua, "  cursor_border = \"{}\",", hex_from_argb(self.cursor));
+        let _ = writeln!(lua, "  selection_fg = \"{}\",", hex_from_argb(self.selection_foreground));
+        let _ = writeln!(lua, "  selection_bg = \"{}\",", hex_from_argb(self.selection_background));
+        let _ = writeln!(lua, "  ansi = {{ {} }},", list(&self.ansi[..8]));
+        let _ = writeln!(lua, "  brights = {{ {} }},", list(&self.ansi[8..]));
+        lua.push_str("}\n");
+        lua
+    }
+
+    /**
+     * A Windows Terminal color scheme object, to add to the `schemes` list
+     * of its `settings.json` under `name`.
+     */
+    pub fn windows_terminal(&self, name: &str) -> String {
+        let mut entries = vec![
+            ("background", self.background),
+            ("foreground", self.foreground),
+            ("cursorColor", self.cursor),
+            ("selectionBackground", self.selection_background),
+        ];
+        entries.extend(WINDOWS_TERMINAL_NAMES.into_iter().zip(self.ansi));
+        let mut json = String::from("{\n");
+        let _ = write!(json, "  \"name\": \"{}\"", escape_double_quoted(name));
+        for (key, argb) in entries {
+            let _ = write!(json, ",\n  \"{key}\": \"{}\"", hex_from_argb(argb));
+        }
+        json.push_str("\n}\n");
+        json
+    }
+
+    /**
+     * X resources for xterm, urxvt and other terminals that read them.
+     */
+    pub fn xresources(&self) -> String {
+        let mut resources = String::new();
+        let _ = writeln!(resources, "*.foreground: {}", hex_from_argb(self.foreground));
+        let _ = writeln!(resources, "*.background: {}", hex_from_argb(self.background));
+        let _ = writeln!(resources, "*.cursorColor: {}", hex_from_argb(self.cursor));
+        for (i, argb) in self.ansi.iter().enumerate() {
+            let _ = writeln!(resources, "*.color{i}: {}", hex_from_argb(*argb));
+        }
+        resources
+    }
+}
+
+/**
+ * The ANSI colors as Windows Terminal names them, which calls magenta
+ * purple.
+ */
+const WINDOWS_TERMINAL_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "purple",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightPurple",
+    "brightCyan",
+    "brightWhite",
+];
+
+/**
+ * xterm's default 256-color palette: its 16 system colors, the 6×6×6 cube
+ * and the 24 step gray ramp.
+ */
+pub fn xterm_256() -> [u32; 256] {
+    const SYSTEM: [u32; 16] = [
+        0xff000000, 0xffcd0000, 0xff00cd00, 0xffcdcd00, 0xff0000ee, 0xffcd00cd, 0xff00cdcd,
+        0xffe5e5e5, 0xff7f7f7f, 0xffff0000, 0xff00ff00, 0xffffff00, 0xff5c5cff, 0xffff00ff,
+        0xff00ffff, 0xffffffff,
+    ];
+    const LEVELS: [u32; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+    core::array::from_fn(|i| match i {
+        0..=15 => SYSTEM[i],
+        16..=231 => {
+            let cube = i - 16;
+            0xff000000 | LEVELS[cube / 36] << 16 | LEVELS[cube / 6 % 6] << 8 | LEVELS[cube % 6]
+        }
+        _ => {
+            let gray = 8 + 10 * (i as u32 - 232);
+            0xff000000 | gray << 16 | gray << 8 | gray
+        }
+    })
+}
+
+/**
+ * Finds the closest color of a 256-color palette, by [`Cam16::distance`],
+ * for terminals without true color. Only the cube and the gray ramp are
+ * matched against: terminal themes redefine the first 16 colors, so what
+ * they look like is not known.
+ *
+ * Building a matcher converts the palette once, so keep it around when
+ * downsampling many colors.
+ */
+#[derive(Debug, Clone)]
+pub struct Ansi256 {
+    cams: [Cam16; 240],
+}
+
+impl Ansi256 {
+    pub fn new(palette: &[u32; 256]) -> Self {
+        Self {
+            cams: core::array::from_fn(|i| palette[i + 16].into()),
+        }
+    }
+
+    /**
+     * A matcher for [`xterm_256`], which most terminals use for indices 16
+     * and up.
+     */
+    pub fn xterm() -> Self {
+        Self::new(&xterm_256())
+    }
+
+    pub fn nearest(&self, argb: u32) -> u8 {
+        let cam = Cam16::from(argb);
+        let (index, _) = self
+            .cams
+            .iter()
+            .enumerate()
+            .map(|(i, other)| (i, cam.distance(other)))
+            .fold((0, f64::INFINITY), |best, next| if next.1 < best.1 { next } else { best });
+        (index + 16) as u8
+    }
+}
+
+fn lerp_lab(from: Lab, to: Lab, t: f64) -> Lab {
+    Lab::new(lerp(from.l, to.l, t), lerp(from.a, to.a, t), lerp(from.b, to.b, t))
+}
@@ -1,7 +1,11 @@
-use super::math::matrix_multiply;
+use super::{
+    lut::{DELINEARIZED_THRESHOLDS, LINEARIZED},
+    math::matrix_multiply,
+};
+import_float!();
 
-const e: f64 = 0.008856451679035631;
-const kappa: f64 = 903.2962962962963;
+const EPSILON: f64 = 0.008856451679035631;
+const KAPPA: f64 = 903.2962962962963;
 
 const WHITE_POINT_D65: [f64; 3] = [95.047, 100.0, 108.883];
 
@@ -29,6 +33,26 @@ pub fn argb_from_rgb(r: u8, g: u8, b: u8) -> u32 {
     255u32 << 24 | (r as u32) << 16 | (g as u32) << 8 | (b as u32)
 }
 
+pub fn alpha_from_argb(argb: u32) -> u32 {
+    (argb >> 24) & 0xff
+}
+
+pub fn argb_with_alpha(argb: u32, alpha: u32) -> u32 {
+    (alpha & 0xff) << 24 | (argb & 0x00ffffff)
+}
+
+pub fn red_from_argb(argb: u32) -> u32 {
+    (argb >> 16) & 0xff
+}
+
+pub fn green_from_argb(argb: u32) -> u32 {
+    (argb >> 8) & 0xff
+}
+
+pub fn blue_from_argb(argb: u32) -> u32 {
+    argb & 0xff
+}
+
 pub fn argb_from_linrgb(linrgb: [f64; 3]) -> u32 {
     argb_from_rgb(
         delinearized(linrgb[0]),
@@ -44,14 +68,48 @@ pub fn argb_from_lstar(lstar: f64) -> u32 {
 }
 
 pub fn argb_from_xyz(x: f64, y: f64, z: f64) -> u32 {
-    let matrix = XYZ_TO_SRGB;
-    let linearR = matrix[0][0] * x + matrix[0][1] * y + matrix[0][2] * z;
-    let linearG = matrix[1][0] * x + matrix[1][1] * y + matrix[1][2] * z;
-    let linearB = matrix[2][0] * x + matrix[2][1] * y + matrix[2][2] * z;
-    let r = delinearized(linearR);
-    let g = delinearized(linearG);
-    let b = delinearized(linearB);
-    argb_from_rgb(r, g, b)
+    argb_from_linrgb(linrgb_from_xyz(x, y, z))
+}
+
+pub fn argb_from_lab(l: f64, a: f64, b: f64) -> u32 {
+    let [x, y, z] = xyz_from_lab(l, a, b);
+    argb_from_xyz(x, y, z)
+}
+
+pub fn lab_from_argb(argb: u32) -> [f64; 3] {
+    let [x, y, z] = xyz_from_argb(argb);
+    lab_from_xyz(x, y, z)
+}
+
+pub fn xyz_from_lab(l: f64, a: f64, b: f64) -> [f64; 3] {
+    let white_point = WHITE_POINT_D65;
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    [
+        lab_invf(fx) * white_point[0],
+        lab_invf(fy) * white_point[1],
+        lab_invf(fz) * white_point[2],
+    ]
+}
+
+pub fn lab_from_xyz(x: f64, y: f64, z: f64) -> [f64; 3] {
+    let white_point = WHITE_POINT_D65;
+    let fx = lab_f(x / white_point[0]);
+    let fy = lab_f(y / white_point[1]);
+    let fz = lab_f(z / white_point[2]);
+    let l = 116.0 * fy - 16.0;
+    let a = 500.0 * (fx - fy);
+    let b = 200.0 * (fy - fz);
+    [l, a, b]
+}
+
+pub fn linrgb_from_xyz(x: f64, y: f64, z: f64) -> [f64; 3] {
+    matrix_multiply([x, y, z], XYZ_TO_SRGB)
+}
+
+pub fn xyz_from_linrgb(linrgb: [f64; 3]) -> [f64; 3] {
+    matrix_multiply(linrgb, SRGB_TO_XYZ)
 }
 
 pub fn y_from_lstar(lstar: f64) -> f64 {
@@ -59,36 +117,53 @@ pub fn y_from_lstar(lstar: f64) -> f64 {
 }
 
 pub fn xyz_from_argb(argb: u32) -> [f64; 3] {
-    let r = linearized(argb & 0x00f00000);
-    let g = linearized(argb & 0x0000ff00);
-    let b = linearized(argb & 0x000000ff);
-    matrix_multiply([r, g, b], SRGB_TO_XYZ)
+    let r = linearized(red_from_argb(argb));
+    let g = linearized(green_from_argb(argb));
+    let b = linearized(blue_from_argb(argb));
+    xyz_from_linrgb([r, g, b])
 }
 
 pub fn lstar_from_argb(argb: u32) -> f64 {
-    let y = xyz_from_argb(argb)[1];
+    lstar_from_y(xyz_from_argb(argb)[1])
+}
+
+pub fn lstar_from_y(y: f64) -> f64 {
     116.0 * lab_f(y / 100.0) - 16.0
 }
 
 pub fn lab_f(t: f64) -> f64 {
-    if t > e {
+    if t > EPSILON {
         t.cbrt()
     } else {
-        (kappa * t + 16.0) / 116.0
+        (KAPPA * t + 16.0) / 116.0
     }
 }
 
 pub fn lab_invf(ft: f64) -> f64 {
     let ft3 = ft * ft * ft;
-    if ft3 > e {
+    if ft3 > EPSILON {
         ft3
     } else {
-        (116.0 * ft - 16.0) / kappa
+        (116.0 * ft - 16.0) / KAPPA
     }
 }
 
-pub fn linearized(rgbComponent: u32) -> f64 {
-    let normalized = rgbComponent as f64 / 255.0;
+/**
+ * Linearizes an 8-bit sRGB channel to the 0..100 scale. Looked up in
+ * [`LINEARIZED`], falling back to [`linearized_exact`] for values above 255.
+ */
+pub fn linearized(rgb_component: u32) -> f64 {
+    match LINEARIZED.get(rgb_component as usize) {
+        Some(linear) => *linear,
+        None => linearized_exact(rgb_component),
+    }
+}
+
+/**
+ * The sRGB transfer function [`linearized`] is tabulated from.
+ */
+pub fn linearized_exact(rgb_component: u32) -> f64 {
+    let normalized = rgb_component as f64 / 255.0;
     if normalized <= 0.040449936 {
         normalized / 12.92 * 100.0
     } else {
@@ -96,8 +171,21 @@ pub fn linearized(rgbComponent: u32) -> f64 {
     }
 }
 
-pub fn delinearized(rgbComponent: f64) -> u8 {
-    let normalized = rgbComponent / 100.0;
+/**
+ * Delinearizes a 0..100 linear channel to 8 bits, clamping out of range
+ * values. Rather than applying the transfer function, this binary searches
+ * [`DELINEARIZED_THRESHOLDS`], which gives the same result as
+ * [`delinearized_exact`].
+ */
+pub fn delinearized(rgb_component: f64) -> u8 {
+    DELINEARIZED_THRESHOLDS.partition_point(|threshold| *threshold <= rgb_component) as u8
+}
+
+/**
+ * The sRGB transfer function [`delinearized`] is tabulated from.
+ */
+pub fn delinearized_exact(rgb_component: f64) -> u8 {
+    let normalized = rgb_component / 100.0;
     let delinearized = {
         if normalized <= 0.0031308 {
             normalized * 12.92
@@ -105,5 +193,5 @@ pub fn delinearized(rgbComponent: f64) -> u8 {
             1.055 * normalized.powf(1.0 / 2.4) - 0.055
         }
     };
-    (delinearized * 255.0).round() as u8
+    (delinearized * 255.0).round().clamp(0.0, 255.0) as u8
 }
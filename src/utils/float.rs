@@ -0,0 +1,69 @@
+/**
+ * The `f64` methods that `core` lacks, provided through `libm` when building
+ * without `std`. Modules doing float math import it with `import_float!()`.
+ */
+#[allow(dead_code)]
+pub(crate) trait Float {
+    fn atan2(self, other: f64) -> f64;
+    fn cbrt(self) -> f64;
+    fn ceil(self) -> f64;
+    fn cos(self) -> f64;
+    fn exp(self) -> f64;
+    fn floor(self) -> f64;
+    fn log(self, base: f64) -> f64;
+    fn powf(self, n: f64) -> f64;
+    fn powi(self, n: i32) -> f64;
+    fn round(self) -> f64;
+    fn sin(self) -> f64;
+    fn sqrt(self) -> f64;
+}
+
+impl Float for f64 {
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn log(self, base: f64) -> f64 {
+        libm::log(self) / libm::log(base)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+}
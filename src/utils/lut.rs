@@ -0,0 +1,141 @@
+/**
+ * `linearized(i)` for every 8-bit channel value `i`, on the 0..100 scale.
+ */
+pub const LINEARIZED: [f64; 256] = [
+    0.0, 0.03035269835488375, 0.0607053967097675, 0.09105809506465125,
+    0.121410793419535, 0.15176349177441875, 0.1821161901293025, 0.21246888848418627,
+    0.24282158683907, 0.2731742851939537, 0.3035269835488375, 0.3346535763899161,
+    0.3676507324047436, 0.40247170184963066, 0.43914420374102936, 0.4776953480693729,
+    0.5181516702338386, 0.5605391624202722, 0.6048833022857054, 0.6512090792594475,
+    0.6995410187265387, 0.7499032043226175, 0.8023192985384995, 0.8568125618069307,
+    0.9134058702220788, 0.9721217320237849, 1.0329823029626937, 1.0960094006488246,
+    1.1612245179743885, 1.2286488356915872, 1.2983032342173013, 1.3702083047289686,
+    1.4443843596092545, 1.520851442291271, 1.599629336550963, 1.6807375752887384,
+    1.7641954488384077, 1.8500220128379696, 1.9382360956935722, 2.02885630566524,
+    2.1219010376003555, 2.217388479338738, 2.315336617811041, 2.4157632448504756,
+    2.518685962736163, 2.6241221894849898, 2.7320891639074896, 2.8426039504420793,
+    2.95568344378088, 3.0713443732993633, 3.1896033073011534, 3.3104766570885054,
+    3.433980680868217, 3.5601314875020345, 3.688945040110004, 3.82043715953465,
+    3.9546235276732835, 4.091519690685319, 4.231141062080967, 4.3735029256973466,
+    4.518620438567554, 4.666508633688009, 4.8171824226889415, 4.970656598412723,
+    5.126945837404324, 5.286064702318025, 5.448027644244237, 5.612849004960009,
+    5.780543019106723, 5.95112381629812, 6.124605423161761, 6.301001765316768,
+    6.480326669290577, 6.662593864377289, 6.8478169844400165, 7.036009569659588,
+    7.227185068231748, 7.421356838014963, 7.618538148130785, 7.818742180518632,
+    8.021982031446832, 8.22827071298148, 8.437621154414881, 8.650046203654977,
+    8.865558628577293, 9.084171118340768, 9.305896284668744, 9.53074666309647,
+    9.758734714186247, 9.989872824711389, 10.224173308810132, 10.461648409110419,
+    10.702310297826761, 10.946171077829932, 11.193242783690561, 11.443537382697373,
+    11.697066775851084, 11.953842798834561, 12.213877222960187, 12.47718175609505,
+    12.743768043564744, 13.013647669036429, 13.286832155381797, 13.563332965520566,
+    13.843161503245183, 14.126329114027165, 14.412847085805778, 14.702726649759498,
+    14.995978981060857, 15.292615199615017, 15.59264637078274, 15.89608350608804,
+    16.2029375639111, 16.513219450166762, 16.826940018969076, 17.14411007328226,
+    17.464740365558505, 17.78884159836291, 18.116424424986022, 18.4474994500441,
+    18.782077230067788, 19.120168274079138, 19.46178304415758, 19.806931955994887,
+    20.155625379439705, 20.507873639031693, 20.863687014525574, 21.223075741405523,
+    21.586050011389926, 21.95261997292692, 22.32279573168085, 22.696587351009835,
+    23.074004852434914, 23.45505821610052, 23.839757381227102, 24.228112246555487,
+    24.620132670783548, 25.015828472995345, 25.415209433082676, 25.818285292159583,
+    26.225065752969623, 26.635560480286248, 27.04977910130658, 27.467731206038465,
+    27.88942634768104, 28.31487404299921, 28.74408377269175, 29.17706498175359,
+    29.613827079832113, 30.05437944157765, 30.49873140698863, 30.946892281750856,
+    31.398871337571755, 31.854677812509184, 32.31432091129508, 32.777809805654215,
+    33.245153634617935, 33.71636150483304, 34.191442490866095, 34.67040563550296,
+    35.15325995004394, 35.640014414594354, 36.13067797835095, 36.62525955988395,
+    37.12376804741491, 37.62621229909065, 38.13260114325301, 38.6429433787049,
+    39.157247774972326, 39.67552307256268, 40.19777798321958, 40.72402119017367,
+    41.25426134839037, 41.788507084813745, 42.32676699860717, 42.86904966139066,
+    43.415363617474895, 43.96571738409188, 44.52011945162278, 45.078578283822345,
+    45.64110231804047, 46.20769996544071, 46.7783796112159, 47.353149614800955,
+    47.93201831008268, 48.514994005607036, 49.10208498478356, 49.693299506087044,
+    50.28864580325687, 50.888132085493375, 51.49176653765214, 52.09955732043543,
+    52.711512570581306, 53.32764040105052, 53.947948901210715, 54.57244613701866,
+    55.201140151200015, 55.83403896342679, 56.471150570492924, 57.11248294648731,
+    57.75804404296506, 58.40784178911641, 59.06188409193369, 59.720178836376334,
+    60.38273388553378, 61.04955708078648, 61.72065624196511, 62.39603916750761,
+    63.07571363461469, 63.75968739940326, 64.44796819705822, 65.14056374198242,
+    65.83748172794485, 66.5387298282272, 67.24431569576875, 67.95424696330939,
+    68.66853124353135, 69.38717612919899, 70.11018919329732, 70.83757798916868,
+    71.56935005064807, 72.30551289219693, 73.04607400903537, 73.79104087727309,
+    74.54042095403875, 75.29422167760778, 76.05245046752924, 76.8151147247507,
+    77.58222183174236, 78.35377915261935, 79.12979403326302, 79.9102738014409,
+    80.69522576692516, 81.48465722161012, 82.27857543962836, 83.07698767746547,
+    83.879901174074, 84.6873231509858, 85.49926081242339, 86.31572134541024,
+    87.13671191987972, 87.96223968878317, 88.79231178819663, 89.62693533742664,
+    90.46611743911495, 91.30986517934193, 92.15818562772947, 93.01108583754237,
+    93.8685728457888, 94.73065367331999, 95.59733532492861, 96.46862478944651,
+    97.34452903984125, 98.22505503331172, 99.11020971138298, 100.0,
+];
+
+/**
+ * The linear values, on the 0..100 scale, half way between consecutive 8-bit
+ * channel values. Entry `k` is where delinearizing starts rounding to `k + 1`
+ * instead of `k`.
+ */
+pub const DELINEARIZED_THRESHOLDS: [f64; 255] = [
+    0.015176349177441876, 0.045529047532325624, 0.07588174588720938, 0.10623444424209313,
+    0.13658714259697685, 0.16693984095186062, 0.19729253930674434, 0.2276452376616281,
+    0.2579979360165119, 0.28835063437139563, 0.3188300904430532, 0.350925934958123,
+    0.3848314933096426, 0.42057480301049466, 0.458183274052838, 0.4976837250274023,
+    0.5391024159806381, 0.5824650784040898, 0.6277969426914107, 0.6751227633498623,
+    0.7244668422128921, 0.775853049866786, 0.829304845476233, 0.8848452951698498,
+    0.942497089126609, 1.0022825574869039, 1.0642236851973577, 1.1283421258858297,
+    1.1946592148522128, 1.2631959812511864, 1.3339731595349034, 1.407011200216447,
+    1.4823302800086415, 1.5599503113873272, 1.6398909516233677, 1.7221716113234105,
+    1.8068114625156377, 1.8938294463134073, 1.9832442801866852, 2.075074464868551,
+    2.1693382909216234, 2.2660538449872063, 2.36523901573795, 2.4669114995532007,
+    2.5710888059345764, 2.6777882626779785, 2.7870270208169257, 2.898822059350997,
+    3.0131901897720907, 3.1301480604002863, 3.2497121605402226, 3.3718988244681087,
+    3.4967242352587946, 3.624204428461639, 3.754355295633311, 3.887192587735158,
+    4.022731918402185, 4.160988767090289, 4.301978482107941, 4.445716283538092,
+    4.592217266055746, 4.741496401646282, 4.893568542229298, 5.048448422192488,
+    5.20615066083972, 5.3666897647573375, 5.5300801301023865, 5.696336044816294,
+    5.865471690767354, 6.037501145825082, 6.212438385869475, 6.390297286737924,
+    6.571091626112461, 6.7548350853498045, 6.941541251256611, 7.131223617812143,
+    7.323895587840543, 7.5195704746346665, 7.7182615035334345, 7.919981813454504,
+    8.124744458384042, 8.332562408825165, 8.543448553206703, 8.757415699253682,
+    8.974476575321063, 9.194643831691977, 9.417930041841839, 9.644347703669503,
+    9.873909240696694, 10.106627003236781, 10.342513269534024, 10.58158024687427,
+    10.8238400726681, 11.069304815507364, 11.317986476196008, 11.569896988756009,
+    11.825048221409341, 12.083451977536606, 12.345119996613247, 12.610063955123938,
+    12.878295467455942, 13.149826086772048, 13.42466730586372, 13.702830557985108,
+    13.984327217668513, 14.269168601521828, 14.55736596900856, 14.848930523210871,
+    15.143873411576273, 15.44220572664832, 15.743938506781891, 16.04908273684337,
+    16.35764934889634, 16.66964922287304, 16.985093187232053, 17.30399201960269,
+    17.62635644741625, 17.95219714852476, 18.281524751807332, 18.614349837764564,
+    18.95068293910138, 19.290534541298456, 19.633915083172692, 19.98083495742689,
+    20.331304511189067, 20.685334046541502, 21.042933821039977, 21.404114048223256,
+    21.76888489811322, 22.137256497705877, 22.50923893145328, 22.884842241736916,
+    23.264076429332462, 23.6469514538663, 24.033477234264016, 24.42366364919083,
+    24.817520537484558, 25.21505769858089, 25.61628489293138, 26.021211842414342,
+    26.429848230738664, 26.842203703840827, 27.258287870275353, 27.678110301598522,
+    28.10168053274597, 28.529008062403893, 28.96010235337422, 29.39497283293396,
+    29.83362889318845, 30.276079891419332, 30.722335150426627, 31.172403958865512,
+    31.62629557157785, 32.08401920991837, 32.54558406207592, 33.010999283389665,
+    33.4802739966603, 33.953417292456834, 34.430438229418264, 34.911345834551085,
+    35.39614910352207, 35.88485700094671, 36.37747846067349, 36.87402238606382,
+    37.37449765026789, 37.87891309649659, 38.38727753828926, 38.89959975977785,
+    39.41588851594697, 39.93615253289054, 40.460400508064545, 40.98864111053629,
+    41.520882981230194, 42.05713473317016, 42.597404951718396, 43.141702194811224,
+    43.6900349931913, 44.24241185063697, 44.798841244188324, 45.35933162437017,
+    45.92389141541209, 46.49252901546552, 47.065252796817916, 47.64207110610409,
+    48.22299226451468, 48.808024568002054, 49.3971762874833, 49.9904556690408,
+    50.587870934119984, 51.189430279724725, 51.79514187861014, 52.40501387947288,
+    53.0190544071392, 53.637271562750364, 54.259673423945976, 54.88626804504493,
+    55.517063457223934, 56.15206766869424, 56.79128866487574, 57.43473440856916,
+    58.08241284012621, 58.734331877617365, 59.39049941699807, 60.05092333227251,
+    60.715611475655585, 61.38457167773311, 62.057811747619894, 62.7353394731159,
+    63.417162620860914, 64.10328893648692, 64.79372614476921, 65.48848194977529,
+    66.18756403501224, 66.89098006357258, 67.59873767827808, 68.31084450182222,
+    69.02730813691093, 69.74813616640164, 70.47333615344107, 71.20291564160104,
+    71.93688215501312, 72.67524319850172, 73.41800625771542, 74.16517879925733,
+    74.9167682708136, 75.67278210128072, 76.43322770089146, 77.1981124613393,
+    77.96744375590167, 78.74122893956174, 79.51947534912904, 80.30219030335869,
+    81.08938110306934, 81.88105503125999, 82.67721935322541, 83.4778813166706,
+    84.28304815182372, 85.09272707154808, 85.90692527145302, 86.72564993000343,
+    87.54890820862819, 88.3767072518277, 89.2090541872801, 90.04595612594655,
+    90.88742016217518, 91.73345337380438, 92.58406282226491, 93.43925555268066,
+    94.29903859396902, 95.16341895893969, 96.03240364439274, 96.9059996312159,
+    97.78421388448044, 98.6670533535366, 99.55452497210776,
+];
@@ -10,9 +10,9 @@ pub fn matrix_multiply(row: [f64; 3], matrix: [[f64; 3]; 3]) -> [f64; 3] {
 }
 
 pub fn sanitize_degrees_double(mut degrees: f64) -> f64 {
-    degrees = degrees % 360.0;
+    degrees %= 360.0;
     if degrees < 0.0 {
-        degrees = degrees + 360.0;
+        degrees += 360.0;
     }
     degrees
 }
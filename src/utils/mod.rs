@@ -1,2 +1,6 @@
 pub mod color;
+#[cfg(not(feature = "std"))]
+pub(crate) mod float;
+pub mod lut;
 pub mod math;
+pub mod string;
@@ -0,0 +1,106 @@
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use super::color::{alpha_from_argb, argb_with_alpha, blue_from_argb, green_from_argb, red_from_argb};
+
+/**
+ * Formats the color as `#rrggbb`, ignoring alpha.
+ */
+pub fn hex_from_argb(argb: u32) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        red_from_argb(argb),
+        green_from_argb(argb),
+        blue_from_argb(argb)
+    )
+}
+
+/**
+ * Formats the color as `#rrggbbaa`.
+ */
+pub fn hex_with_alpha_from_argb(argb: u32) -> String {
+    format!("{}{:02x}", hex_from_argb(argb), alpha_from_argb(argb))
+}
+
+/**
+ * Parses `rgb`, `rrggbb` or `rrggbbaa`, with or without a leading `#`.
+ */
+pub fn argb_from_hex(hex: &str) -> Option<u32> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        3 => {
+            let value = u32::from_str_radix(hex, 16).ok()?;
+            let (r, g, b) = ((value >> 8) & 0xf, (value >> 4) & 0xf, value & 0xf);
+            Some(0xff000000 | (r * 0x11) << 16 | (g * 0x11) << 8 | (b * 0x11))
+        }
+        6 => Some(0xff000000 | u32::from_str_radix(hex, 16).ok()?),
+        8 => {
+            let value = u32::from_str_radix(hex, 16).ok()?;
+            Some(argb_with_alpha(value >> 8, value & 0xff))
+        }
+        _ => None,
+    }
+}
+
+/**
+ * Escapes `value` for use between double quotes in JSON, or in YAML, whose
+ * double-quoted strings accept the same escapes.
+ */
+pub fn escape_double_quoted(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/**
+ * Serializes colors as hex strings instead of their default representation,
+ * for use as `#[serde(with = "material_rs::utils::string::hex")]` on fields of
+ * any type that converts to and from ARGB. Opaque colors are written as
+ * `#rrggbb`, others as `#rrggbbaa`; anything [`argb_from_hex`] accepts is
+ * read.
+ */
+#[cfg(feature = "serde")]
+pub mod hex {
+    use alloc::string::String;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use super::{argb_from_hex, hex_from_argb, hex_with_alpha_from_argb};
+    use crate::utils::color::alpha_from_argb;
+
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Copy + Into<u32>,
+        S: Serializer,
+    {
+        let argb: u32 = (*color).into();
+        if alpha_from_argb(argb) == 0xff {
+            serializer.serialize_str(&hex_from_argb(argb))
+        } else {
+            serializer.serialize_str(&hex_with_alpha_from_argb(argb))
+        }
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: From<u32>,
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        argb_from_hex(&hex)
+            .map(C::from)
+            .ok_or_else(|| D::Error::custom("expected a hex color"))
+    }
+}
@@ -0,0 +1,40 @@
+use material_rs::{base16::Base24, contrast::ratio_of_argb, hct::HCT, utils::math::difference_degrees};
+
+#[test]
+fn base24_neutrals_step_away_from_background() {
+    for base in [Base24::dark(0xff4285f4), Base24::light(0xff4285f4)] {
+        let background = base.colors[0];
+        let ratios: Vec<f64> = base.colors[1..8]
+            .iter()
+            .map(|argb| ratio_of_argb(*argb, background))
+            .collect();
+        assert!(ratios.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(ratio_of_argb(base.colors[5], background) >= 7.0);
+        for argb in &base.colors[8..0x0f] {
+            assert!(ratio_of_argb(*argb, background) >= 3.0);
+        }
+    }
+}
+
+#[test]
+fn base24_brights_keep_their_accent_hues() {
+    let base = Base24::dark(0xff4285f4);
+    for (accent, bright) in [(0x08, 0x12), (0x0a, 0x13), (0x0b, 0x14), (0x0d, 0x16)] {
+        let (accent, bright) = (HCT::from(base.colors[accent]), HCT::from(base.colors[bright]));
+        assert!(difference_degrees(accent.hue(), bright.hue()) < 15.0);
+        assert!(bright.tone() > accent.tone());
+    }
+}
+
+#[test]
+fn base16_yaml_follows_spec() {
+    let base = Base24::light(0xff4285f4);
+    let yaml = base.base16_yaml("Material \"Blue\"", "material-rs");
+    assert!(yaml.starts_with("system: \"base16\"\nname: \"Material \\\"Blue\\\"\"\n"));
+    assert!(yaml.contains("variant: \"light\"\npalette:\n  base00: \"#"));
+    assert!(yaml.contains("  base0F: \"#"));
+    assert!(!yaml.contains("base10"));
+    let yaml = base.base24_yaml("Material", "material-rs");
+    assert_eq!(yaml.matches("  base").count(), 24);
+    assert!(yaml.contains("  base17: \"#"));
+}
@@ -0,0 +1,27 @@
+use material_rs::hct::{
+    batch::{argb_from_hct_slice, hct_from_argb_slice},
+    HCT,
+};
+
+#[test]
+fn batch_conversion_matches_single_conversion() {
+    let argbs: Vec<u32> = (0..1000u32)
+        .map(|i| 0xff000000 | i.wrapping_mul(2654435761) >> 8)
+        .chain((0..=0xffffffu32).step_by(0x010307).map(|argb| 0xff000000 | argb))
+        .chain([0x80ff0000, 0x00000000, 0xffffffff])
+        .collect();
+    let hcts = hct_from_argb_slice(&argbs);
+    assert_eq!(hcts.len(), argbs.len());
+    for (hct, &argb) in hcts.iter().zip(&argbs) {
+        let expected = HCT::from(argb);
+        assert_eq!(hct.hue(), expected.hue());
+        assert_eq!(hct.chroma(), expected.chroma());
+        assert_eq!(hct.tone(), expected.tone());
+    }
+    assert_eq!(argb_from_hct_slice(&hcts), argbs);
+}
+
+#[test]
+fn batch_conversion_of_empty_slice() {
+    assert!(hct_from_argb_slice(&[]).is_empty());
+}
@@ -0,0 +1,64 @@
+use material_rs::{
+    blend::{
+        harmonize, hct_hue, overlay, source_over, state_layer, surface_tint, BlendSpace, State,
+    },
+    scheme::Scheme,
+};
+
+#[test]
+fn harmonize_keeps_design_alpha() {
+    let harmonized = harmonize(0x80ff0000, 0xff0000ff);
+    assert_eq!(harmonized >> 24, 0x80);
+    assert_eq!(harmonized | 0xff000000, harmonize(0xffff0000, 0xff0000ff));
+}
+
+#[test]
+fn hct_hue_interpolates_alpha() {
+    assert_eq!(hct_hue(0x00ff0000, 0xff0000ff, 0.5) >> 24, 0x80);
+    assert_eq!(hct_hue(0x40ff0000, 0x40ff0000, 0.3) >> 24, 0x40);
+}
+
+#[test]
+fn source_over_composites() {
+    assert_eq!(source_over(0xffff0000, 0xff0000ff, BlendSpace::Srgb), 0xffff0000);
+    assert_eq!(source_over(0x00ff0000, 0xff0000ff, BlendSpace::Srgb), 0xff0000ff);
+    assert_eq!(source_over(0x80ffffff, 0xff000000, BlendSpace::Srgb), 0xff808080);
+    assert_eq!(source_over(0x80ffffff, 0xff000000, BlendSpace::Linear), 0xffbcbcbc);
+    assert_eq!(source_over(0x80ffffff, 0x80000000, BlendSpace::Srgb) >> 24, 0xc0);
+    assert_eq!(source_over(0x00ffffff, 0x00000000, BlendSpace::Srgb), 0);
+}
+
+#[test]
+fn state_layers_use_standard_opacities() {
+    assert_eq!(
+        state_layer(0xffffffff, 0xff000000, State::Hover, BlendSpace::Srgb),
+        overlay(0xffffffff, 0.08, 0xff000000, BlendSpace::Srgb)
+    );
+    assert_eq!(state_layer(0xffffffff, 0xff000000, State::Dragged, BlendSpace::Srgb), 0xff292929);
+}
+
+#[test]
+fn scheme_state_colors_cover_every_role() {
+    let scheme = Scheme::light(0xff4285f4);
+    let states = scheme.state_colors(BlendSpace::Srgb);
+    assert_eq!(states.len(), scheme.roles().len());
+    let (_, primary) = states.iter().find(|(name, _)| *name == "primary").unwrap();
+    assert_eq!(
+        primary.hover,
+        state_layer(scheme.on_primary(), primary.enabled, State::Hover, BlendSpace::Srgb)
+    );
+    assert_ne!(primary.pressed, primary.enabled);
+}
+
+#[test]
+fn surface_tint_follows_elevation_levels() {
+    let scheme = Scheme::dark(0xff4285f4);
+    let (primary, surface) = (scheme.primary(), scheme.surface());
+    assert_eq!(scheme.surface_at_elevation(0), surface);
+    assert_eq!(
+        scheme.surface_at_elevation(3),
+        overlay(primary, 0.11, surface, BlendSpace::Srgb)
+    );
+    assert_eq!(scheme.surface_at_elevation(9), surface_tint(surface, primary, 5));
+    assert_ne!(scheme.surface_at_elevation(5), scheme.surface_at_elevation(1));
+}
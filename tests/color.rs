@@ -0,0 +1,23 @@
+use material_rs::utils::color::{
+    delinearized, delinearized_exact, linearized, linearized_exact,
+};
+
+#[test]
+fn linearized_table_matches_transfer_function() {
+    for component in 0..=300 {
+        let (table, exact) = (linearized(component), linearized_exact(component));
+        assert!((table - exact).abs() < 1e-12, "{component}: {table} != {exact}");
+    }
+}
+
+#[test]
+fn delinearized_search_matches_transfer_function() {
+    for i in -1000..=101_000 {
+        let linear = i as f64 / 1000.0;
+        assert_eq!(delinearized(linear), delinearized_exact(linear), "{linear}");
+    }
+    for component in 0..=255 {
+        assert_eq!(delinearized(linearized(component)) as u32, component);
+    }
+    assert_eq!(delinearized(f64::NAN), delinearized_exact(f64::NAN));
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use material_rs::{
+    contrast::{
+        apca::{lc, tone_for_lc, Polarity},
+        audit::{Level, Usage},
+        darker, lighter, ratio_of_argb, ratio_of_tones,
+    },
+    hct::HCT,
+    palette::TonalPalette,
+    scheme::Scheme,
+};
+
+use common::assert_close;
+
+#[test]
+fn wcag_ratios() {
+    assert_close(ratio_of_tones(0.0, 100.0), 21.0, 1e-9);
+    assert_close(ratio_of_tones(50.0, 50.0), 1.0, 1e-9);
+    assert_close(ratio_of_argb(0xff000000, 0xffffffff), 21.0, 0.01);
+    let light = lighter(40.0, 4.5).unwrap();
+    assert!(ratio_of_tones(light, 40.0) >= 4.5);
+    let dark = darker(90.0, 4.5).unwrap();
+    assert!(ratio_of_tones(dark, 90.0) >= 4.5);
+    assert_eq!(lighter(90.0, 4.5), None);
+}
+
+#[test]
+fn apca_reference_values() {
+    assert_close(lc(0xff000000, 0xffffffff), 106.04, 0.01);
+    assert_close(lc(0xffffffff, 0xff000000), -107.88, 0.01);
+    assert_close(lc(0xff888888, 0xffffffff), 63.06, 0.01);
+    assert_close(lc(0xffffffff, 0xff888888), -68.54, 0.01);
+    assert_eq!(lc(0xff777777, 0xff777777), 0.0);
+    assert_eq!(Polarity::of(0xffffffff, 0xff000000), Polarity::LightOnDark);
+}
+
+#[test]
+fn finds_tone_reaching_target_lc() {
+    let palette = TonalPalette::from(0xff4285f4);
+    let background = 0xffffffff;
+    let tone = tone_for_lc(&palette, background, 75.0, Polarity::DarkOnLight).unwrap();
+    assert!(lc(palette.tone(tone), background) >= 75.0);
+    assert!(lc(palette.tone(tone + 1.0), background) < 76.0);
+
+    let dark = 0xff101010;
+    let tone = tone_for_lc(&palette, dark, 60.0, Polarity::LightOnDark).unwrap();
+    assert!(lc(palette.tone(tone), dark) <= -60.0);
+
+    assert_eq!(tone_for_lc(&palette, 0xff777777, 100.0, Polarity::DarkOnLight), None);
+}
+
+#[test]
+fn audits_scheme_pairs() {
+    let report = Scheme::light(0xff4285f4).audit();
+    assert_eq!(report.checks.len(), 13);
+    assert!(report.passes(Level::AA), "{:?}", report.failures(Level::AA).collect::<Vec<_>>());
+    let outline = report.checks.last().unwrap();
+    assert_eq!((outline.foreground, outline.background), ("outline", "surface"));
+    assert_eq!(outline.usage, Usage::NonText);
+    let primary = &report.checks[0];
+    assert_eq!((primary.foreground, primary.background), ("onPrimary", "primary"));
+    assert_eq!(primary.passes(Level::AAA), primary.ratio >= 7.0);
+    assert_eq!(report.aaa, report.failures(Level::AAA).next().is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn audit_report_json() {
+    let json = Scheme::light(0xff4285f4).audit().json().unwrap();
+    assert!(json.contains("\"foreground\":\"onPrimary\""));
+    assert!(json.contains("\"aaLargeText\":"));
+    assert!(json.contains("\"usage\":\"nonText\""));
+}
+
+#[test]
+fn repairs_user_overrides() {
+    let mut scheme = Scheme::light(0xff4285f4);
+    assert!(scheme.set_role("primary", 0xffb0c4ff));
+    assert!(!scheme.set_role("notARole", 0xff000000));
+    let before = scheme.on_primary();
+
+    let repairs = scheme.repair(4.5);
+    assert_eq!(repairs.len(), 1);
+    let repair = &repairs[0];
+    assert_eq!((repair.role, repair.against), ("onPrimary", "primary"));
+    assert_eq!(repair.before, before);
+    assert_eq!(repair.after, scheme.on_primary());
+    assert!(repair.ratio_before < 4.5 && repair.ratio_after >= 4.5);
+
+    let original: HCT = before.into();
+    let repaired: HCT = scheme.on_primary().into();
+    assert!(repaired.tone() < original.tone());
+    assert!(scheme.audit().passes(Level::AA));
+    assert!(scheme.repair(4.5).is_empty());
+}
+
+#[test]
+fn repair_keeps_hue_of_chromatic_roles() {
+    let mut scheme = Scheme::light(0xff4285f4);
+    let original = HCT::new(20.0, 40.0, 60.0);
+    scheme.set_role("onPrimaryContainer", original.argb());
+    scheme.repair(7.0);
+    let repaired: HCT = scheme.on_primary_container().into();
+    assert!((repaired.hue() - original.hue()).abs() < 3.0, "hue {}", repaired.hue());
+}
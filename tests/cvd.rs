@@ -0,0 +1,39 @@
+use material_rs::{
+    cvd::{check_accents, simulate, Deficiency},
+    hct::HCT,
+    scheme::Scheme,
+};
+
+#[test]
+fn simulates_deficiencies() {
+    assert_eq!(simulate(0xffffffff, Deficiency::Protanopia), 0xffffffff);
+    assert_eq!(simulate(0x80000000, Deficiency::Tritanopia), 0x80000000);
+    let grey: HCT = simulate(0xffff0000, Deficiency::Achromatopsia).into();
+    assert!(grey.chroma() < 2.0);
+    let red: HCT = 0xffff0000.into();
+    assert!((grey.tone() - red.tone()).abs() < 1.0);
+    assert_ne!(simulate(0xffff0000, Deficiency::Protanopia), 0xffff0000);
+}
+
+#[test]
+fn simulates_whole_schemes() {
+    let scheme = Scheme::light(0xff4285f4);
+    let simulated = scheme.simulate(Deficiency::Deuteranopia);
+    for ((name, original), (_, argb)) in scheme.roles().into_iter().zip(simulated.roles()) {
+        assert_eq!(argb, simulate(original, Deficiency::Deuteranopia), "{name}");
+    }
+}
+
+#[test]
+fn checks_accent_distinguishability() {
+    let scheme = Scheme::light(0xff4285f4);
+    let results = check_accents(&scheme, 5.0);
+    assert_eq!(results.len(), Deficiency::ALL.len());
+    for result in &results {
+        assert_eq!(result.distinguishable, result.distance >= 5.0);
+        assert_ne!(result.closest.0, result.closest.1);
+    }
+    let achromatopsia = &results[3];
+    assert_eq!(achromatopsia.deficiency, Deficiency::Achromatopsia);
+    assert!(!check_accents(&scheme, 1000.0).iter().any(|r| r.distinguishable));
+}
@@ -0,0 +1,65 @@
+use material_rs::{
+    dataviz::{diverging, sequential, Categorical},
+    hct::{cam16::Cam16, HCT},
+};
+
+#[test]
+fn categorical_colors_are_distinct_and_in_band() {
+    let colors = Categorical::new(0xff4285f4).generate(8);
+    assert_eq!(colors.len(), 8);
+    let seed: HCT = 0xff4285f4.into();
+    let first: HCT = colors[0].into();
+    assert!((first.hue() - seed.hue()).abs() < 2.0);
+    for (i, a) in colors.iter().enumerate() {
+        let hct: HCT = (*a).into();
+        assert!((49.5..=70.5).contains(&hct.tone()), "tone {}", hct.tone());
+        assert!(hct.chroma() >= 35.0, "chroma {}", hct.chroma());
+        for b in &colors[i + 1..] {
+            assert!(Cam16::from(*a).distance(&Cam16::from(*b)) > 10.0);
+        }
+    }
+}
+
+#[test]
+fn categorical_respects_options() {
+    let colors = Categorical::new(0xff4285f4)
+        .with_tone_range(30.0, 40.0)
+        .with_min_chroma(20.0)
+        .generate(4);
+    for argb in colors {
+        let hct: HCT = argb.into();
+        assert!((29.5..=40.5).contains(&hct.tone()));
+    }
+}
+
+#[test]
+fn categorical_colors_stay_above_min_chroma() {
+    // Blue can't reach this chroma within the default tone band.
+    let colors = Categorical::new(0xff4285f4).with_min_chroma(70.0).generate(4);
+    assert!(!colors.is_empty());
+    for argb in colors {
+        let hct: HCT = argb.into();
+        assert!(hct.chroma() >= 69.5, "chroma {}", hct.chroma());
+    }
+}
+
+#[test]
+fn sequential_ramp_darkens() {
+    let ramp = sequential(0xff4285f4, 5);
+    assert_eq!(ramp.len(), 5);
+    let tones: Vec<f64> = ramp.iter().map(|argb| HCT::from(*argb).tone()).collect();
+    assert!(tones.windows(2).all(|w| w[0] > w[1]));
+}
+
+#[test]
+fn diverging_ramp_is_light_in_the_middle() {
+    let ramp = diverging(0xff4285f4, 7);
+    assert_eq!(ramp.len(), 7);
+    let tones: Vec<f64> = ramp.iter().map(|argb| HCT::from(*argb).tone()).collect();
+    assert!(tones[3] > tones[2] && tones[3] > tones[4]);
+    assert!(tones[0] < tones[2] && tones[6] < tones[4]);
+    let low: HCT = ramp[0].into();
+    let high: HCT = ramp[6].into();
+    assert!((low.hue() - high.hue()).abs() > 90.0);
+    assert_eq!(diverging(0xff4285f4, 6).len(), 6);
+}
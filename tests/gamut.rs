@@ -0,0 +1,52 @@
+use material_rs::{
+    hct::{
+        gamut::{Gamut, GamutRgb},
+        solver::{solve_to_gamut, solve_to_int},
+    },
+    utils::color::argb_from_rgb,
+};
+
+fn quantized(rgb: GamutRgb) -> u32 {
+    let channel = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+    argb_from_rgb(channel(rgb.r), channel(rgb.g), channel(rgb.b))
+}
+
+#[test]
+fn srgb_gamut_matches_solve_to_int() {
+    for (hue, chroma, tone) in [(27.0, 40.0, 50.0), (140.0, 200.0, 80.0), (282.0, 87.0, 32.0)] {
+        let rgb = solve_to_gamut(hue, chroma, tone, Gamut::Srgb);
+        assert_eq!(quantized(rgb), solve_to_int(hue, chroma, tone));
+    }
+}
+
+#[test]
+fn in_gamut_colors_agree_across_gamuts() {
+    for gamut in [Gamut::DisplayP3, Gamut::Rec2020] {
+        let rgb = solve_to_gamut(220.0, 30.0, 60.0, gamut).to_gamut(Gamut::Srgb);
+        let expected = solve_to_int(220.0, 30.0, 60.0);
+        let actual = quantized(rgb);
+        for shift in [0, 8, 16] {
+            let e = (expected >> shift) & 0xff;
+            let a = (actual >> shift) & 0xff;
+            assert!(e.abs_diff(a) <= 1, "{expected:08x} vs {actual:08x}");
+        }
+    }
+}
+
+#[test]
+fn display_p3_reaches_beyond_srgb() {
+    let p3 = solve_to_gamut(142.0, 200.0, 80.0, Gamut::DisplayP3);
+    for c in [p3.r, p3.g, p3.b] {
+        assert!((-0.001..=1.001).contains(&c));
+    }
+    let srgb = p3.to_gamut(Gamut::Srgb);
+    assert!([srgb.r, srgb.g, srgb.b].iter().any(|c| !(0.0..=1.0).contains(c)));
+}
+
+#[test]
+fn formats_css_color() {
+    let rgb = GamutRgb::new(Gamut::DisplayP3, 1.0, 0.5, 0.0);
+    assert_eq!(rgb.to_string(), "color(display-p3 1.0000 0.5000 0.0000)");
+    let grey = solve_to_gamut(0.0, 0.0, 100.0, Gamut::Rec2020);
+    assert_eq!(grey.to_string(), "color(rec2020 1.0000 1.0000 1.0000)");
+}
@@ -0,0 +1,59 @@
+use material_rs::{
+    gradient::{Gradient, GradientMode, GradientSpace},
+    hct::HCT,
+};
+
+#[test]
+fn stops_include_key_colors() {
+    let keys = [0xffff0000, 0xff00ff00, 0xff0000ff];
+    for space in [GradientSpace::Hct, GradientSpace::Cam16Ucs, GradientSpace::OkLab] {
+        let stops = Gradient::new(&keys).with_space(space).stops(5);
+        assert_eq!(stops.len(), 5);
+        assert_eq!(stops[0], keys[0]);
+        assert_eq!(stops[2], keys[1]);
+        assert_eq!(stops[4], keys[2]);
+    }
+}
+
+#[test]
+fn hct_takes_the_shorter_hue_arc() {
+    let from = HCT::new(350.0, 40.0, 50.0).argb();
+    let to = HCT::new(30.0, 40.0, 50.0).argb();
+    let mid: HCT = Gradient::new(&[from, to]).at(0.5).into();
+    assert!(mid.hue() < 20.0 || mid.hue() > 340.0, "hue {}", mid.hue());
+}
+
+#[test]
+fn preserving_modes_hold_first_key() {
+    let keys = [HCT::new(30.0, 40.0, 30.0).argb(), HCT::new(200.0, 20.0, 80.0).argb()];
+    let first: HCT = keys[0].into();
+    for argb in Gradient::new(&keys).with_mode(GradientMode::PreserveTone).stops(6) {
+        let hct: HCT = argb.into();
+        assert!((hct.tone() - first.tone()).abs() < 1.0);
+    }
+    for argb in Gradient::new(&keys).with_mode(GradientMode::PreserveChroma).stops(6) {
+        let hct: HCT = argb.into();
+        assert!((hct.chroma() - first.chroma()).abs() < 2.0);
+    }
+}
+
+#[test]
+fn alpha_is_interpolated_between_keys() {
+    let keys = [0x00ff0000, 0xff0000ff];
+    for space in [GradientSpace::Hct, GradientSpace::Cam16Ucs, GradientSpace::OkLab] {
+        let stops = Gradient::new(&keys).with_space(space).stops(3);
+        assert_eq!(stops[0], keys[0]);
+        assert_eq!(stops[1] >> 24, 0x80);
+        assert_eq!(stops[2], keys[1]);
+    }
+    let css = Gradient::new(&keys).css(2, 0.0);
+    assert_eq!(css, "linear-gradient(0deg, #ff000000 0%, #0000ff 100%)");
+}
+
+#[test]
+fn formats_css_linear_gradient() {
+    let css = Gradient::new(&[0xff000000, 0xffffffff]).css(3, 90.0);
+    assert!(css.starts_with("linear-gradient(90deg, #000000 0%, #"));
+    assert!(css.ends_with(" 50%, #ffffff 100%)"));
+    assert_eq!(Gradient::new(&[]).stops(3), vec![0, 0, 0]);
+}
@@ -1 +1,121 @@
+mod common;
 
+use material_rs::{
+    hct::{
+        cam16::Cam16,
+        vc::{ViewingConditions, ViewingConditionsBuilder},
+        HCT,
+    },
+    space::rgb::Rgb,
+};
+
+use common::assert_close;
+
+#[test]
+fn hct_from_argb() {
+    let red: HCT = 0xffff0000.into();
+    assert_close(red.hue(), 27.408, 0.001);
+    assert_close(red.chroma(), 113.357, 0.001);
+    assert_close(red.tone(), 53.233, 0.001);
+
+    let blue: HCT = 0xff0000ff.into();
+    assert_close(blue.hue(), 282.788, 0.001);
+    assert_close(blue.chroma(), 87.230, 0.001);
+    assert_close(blue.tone(), 32.302, 0.001);
+}
+
+#[test]
+fn hct_solves_to_argb() {
+    assert_eq!(HCT::new(27.408, 113.357, 53.233).argb(), 0xffff0000);
+    assert_eq!(HCT::new(282.788, 87.230, 32.302).argb(), 0xff0000ff);
+    assert_eq!(HCT::new(142.139, 108.410, 87.737).argb(), 0xff00ff00);
+}
+
+#[test]
+fn hct_round_trips_grid() {
+    for argb in (0..=0xffffffu32).step_by(0x010307) {
+        let argb = 0xff000000 | argb;
+        let hct: HCT = argb.into();
+        assert_eq!(HCT::new(hct.hue(), hct.chroma(), hct.tone()).argb(), argb);
+    }
+}
+
+#[test]
+fn hct_clamps_out_of_gamut_chroma() {
+    let hct = HCT::new(120.0, 200.0, 50.0);
+    assert_close(hct.tone(), 50.0, 0.5);
+    assert!(hct.chroma() < 200.0);
+}
+
+#[test]
+fn unquantized_hct_keeps_requested_coordinates() {
+    for (hue, chroma, tone) in [(20.0, 30.0, 40.0), (150.5, 12.25, 71.3), (300.0, 45.0, 55.5)] {
+        let hct = HCT::new_unquantized(hue, chroma, tone);
+        assert_close(hct.hue(), hue, 0.01);
+        assert_close(hct.chroma(), chroma, 0.01);
+        assert_close(hct.tone(), tone, 0.01);
+        assert_eq!(hct.argb(), HCT::new(hue, chroma, tone).argb());
+    }
+}
+
+#[test]
+fn setters_keep_unquantized_precision() {
+    let mut hct = HCT::new_unquantized(150.5, 12.25, 71.3);
+    hct.set_alpha(0x80);
+    hct.set_tone(42.7);
+    assert_close(hct.tone(), 42.7, 0.01);
+    assert_close(hct.chroma(), 12.25, 0.01);
+    let fractional = |channel: f64| (channel * 255.0 - (channel * 255.0).round()).abs() > 1e-6;
+    let rgb = hct.rgb();
+    assert!(fractional(rgb.r) || fractional(rgb.g) || fractional(rgb.b));
+    assert_eq!(hct.alpha(), 0x80);
+}
+
+#[test]
+fn cam16_round_trips_unquantized_rgb() {
+    let rgb = Rgb::new(0.1234567, 0.7654321, 0.5);
+    let cam: Cam16 = rgb.into();
+    let back: Rgb<f64> = cam.into();
+    assert_close(back.r, rgb.r, 1e-6);
+    assert_close(back.g, rgb.g, 1e-6);
+    assert_close(back.b, rgb.b, 1e-6);
+
+    let hct: HCT = rgb.into();
+    assert_eq!(hct.rgb(), rgb);
+    assert_eq!(hct.argb(), u32::from(rgb));
+}
+
+#[test]
+fn hct_preserves_alpha() {
+    let mut hct: HCT = 0x80ff0000.into();
+    assert_eq!(hct.alpha(), 0x80);
+    assert_eq!(hct.argb(), 0x80ff0000);
+    hct.set_tone(80.0);
+    assert_eq!(hct.alpha(), 0x80);
+    hct.set_alpha(0x20);
+    assert_eq!(hct.argb() >> 24, 0x20);
+    hct.set_argb(0xff00ff00);
+    assert_eq!(hct.alpha(), 0xff);
+}
+
+#[test]
+fn default_viewing_conditions_match_builder() {
+    let built = ViewingConditionsBuilder::new().build();
+    let default = ViewingConditions::DEFAULT;
+    for (a, b) in [
+        (built.n, default.n),
+        (built.aw, default.aw),
+        (built.nbb, default.nbb),
+        (built.ncb, default.ncb),
+        (built.c, default.c),
+        (built.nc, default.nc),
+        (built.rgb_d[0], default.rgb_d[0]),
+        (built.rgb_d[1], default.rgb_d[1]),
+        (built.rgb_d[2], default.rgb_d[2]),
+        (built.fl, default.fl),
+        (built.f_l_root, default.f_l_root),
+        (built.z, default.z),
+    ] {
+        assert_close(a, b, 1e-12);
+    }
+}
@@ -0,0 +1,65 @@
+use material_rs::palette::TonalPalette;
+
+#[test]
+fn tonal_palette_preserves_alpha() {
+    let palette = TonalPalette::from(0x664285f4);
+    assert_eq!(palette.tone(40.0) >> 24, 0x66);
+    assert_eq!(palette.tone(100.0), 0x66ffffff);
+    assert_eq!(TonalPalette::from(0xff4285f4).tone(0.0), 0xff000000);
+}
+
+#[test]
+fn key_color_with_exact_chroma() {
+    let key = TonalPalette::from_hue_and_chroma(50.0, 60.0).key_color();
+    assert!((key.hue() - 50.0).abs() < 10.0);
+    assert!((key.chroma() - 60.0).abs() < 0.5);
+    assert!(key.tone() > 0.0 && key.tone() < 100.0);
+}
+
+#[test]
+fn key_color_with_unreachable_chroma() {
+    let key = TonalPalette::from_hue_and_chroma(149.0, 200.0).key_color();
+    assert!((key.hue() - 149.0).abs() < 10.0);
+    assert!(key.chroma() > 89.0);
+    assert!(key.tone() > 0.0 && key.tone() < 100.0);
+}
+
+#[test]
+fn key_color_with_low_chroma_stays_near_pivot() {
+    let key = TonalPalette::from_hue_and_chroma(50.0, 3.0).key_color();
+    assert!((key.chroma() - 3.0).abs() < 0.5);
+    assert!((key.tone() - 50.0).abs() < 0.5);
+}
+
+#[test]
+fn key_color_of_seeded_palette_is_the_seed() {
+    let palette = TonalPalette::from(0xff4285f4);
+    assert_eq!(palette.key_color().argb(), 0xff4285f4);
+}
+
+#[test]
+fn key_color_is_found_on_first_use() {
+    let palette = TonalPalette::from_hue_and_chroma(50.0, 60.0);
+    let copy = palette.clone();
+    let key = palette.key_color();
+    assert_eq!(palette.key_color().argb(), key.argb());
+    assert_eq!(copy.key_color().argb(), key.argb());
+}
+
+#[test]
+fn tonal_palette_is_shareable_across_threads() {
+    fn assert_send_sync<T: Send + Sync + Clone>() {}
+    assert_send_sync::<TonalPalette>();
+
+    let palette = std::sync::Arc::new(TonalPalette::from(0xff4285f4));
+    let expected = TonalPalette::clone(&palette).tone(40.0);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let palette = palette.clone();
+            std::thread::spawn(move || palette.tone(40.0))
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
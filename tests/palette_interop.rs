@@ -0,0 +1,59 @@
+#![cfg(feature = "palette-interop")]
+
+mod common;
+
+use material_rs::{
+    hct::{cam16::Cam16, HCT},
+    space::lab::Lab,
+};
+use palette::{IntoColor, LinSrgb, Oklch, Srgb, Srgba};
+
+use common::assert_close;
+
+#[test]
+fn hct_converts_to_and_from_srgb() {
+    let hct = HCT::from(0xff4285f4);
+    let srgb: Srgb<u8> = hct.into();
+    assert_eq!((srgb.red, srgb.green, srgb.blue), (0x42, 0x85, 0xf4));
+    assert_eq!(HCT::from(srgb).argb(), 0xff4285f4);
+
+    let hct = HCT::new_unquantized(120.0, 40.0, 60.0);
+    let back = HCT::from(Srgb::<f64>::from(hct));
+    assert_close(back.hue(), hct.hue(), 1e-9);
+    assert_close(back.chroma(), hct.chroma(), 1e-9);
+    assert_close(back.tone(), hct.tone(), 1e-9);
+}
+
+#[test]
+fn hct_keeps_alpha_through_srgba() {
+    let srgba: Srgba<u8> = HCT::from(0x804285f4).into();
+    assert_eq!(srgba.alpha, 0x80);
+    assert_eq!(HCT::from(srgba).argb(), 0x804285f4);
+}
+
+#[test]
+fn lab_and_oklch_agree_with_palette() {
+    let hct = HCT::from(0xffff0000);
+    let lab: palette::Lab<palette::white_point::D65, f64> = hct.into();
+    let ours = Lab::from(0xffff0000);
+    assert_eq!((lab.l, lab.a, lab.b), (ours.l, ours.a, ours.b));
+
+    let oklch: Oklch<f64> = hct.into();
+    let theirs: Oklch<f64> = Srgb::new(1.0, 0.0, 0.0).into_linear::<f64>().into_color();
+    assert_close(oklch.l, theirs.l, 1e-4);
+    assert_close(oklch.chroma, theirs.chroma, 1e-4);
+    assert_close(
+        oklch.hue.into_positive_degrees(),
+        theirs.hue.into_positive_degrees(),
+        1e-2,
+    );
+
+    let linear: LinSrgb<f64> = hct.into();
+    assert_close(linear.red, 1.0, 1e-9);
+}
+
+#[test]
+fn cam16_converts_through_srgb() {
+    let srgb: Srgb<f64> = Cam16::from(0xff4285f4).into();
+    assert_eq!(u32::from(Cam16::from(srgb)), 0xff4285f4);
+}
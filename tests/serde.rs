@@ -0,0 +1,80 @@
+#![cfg(feature = "serde")]
+
+use material_rs::{
+    contrast::audit::{Level, Usage},
+    hct::HCT,
+    palette::TonalPalette,
+    scheme::Scheme,
+    space::oklab::OkLch,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Theme {
+    #[serde(with = "material_rs::utils::string::hex")]
+    seed: u32,
+    #[serde(with = "material_rs::utils::string::hex")]
+    accent: HCT,
+    #[serde(with = "material_rs::utils::string::hex")]
+    overlay: OkLch,
+}
+
+#[test]
+fn hct_round_trips_as_coordinates() {
+    let mut hct = HCT::from(0xff4285f4);
+    hct.set_alpha(0x80);
+    let json = serde_json::to_string(&hct).unwrap();
+    assert!(json.contains("\"hue\":") && json.contains("\"alpha\":128"));
+    let back: HCT = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.argb(), hct.argb());
+
+    let opaque: HCT = serde_json::from_str(r#"{"hue":0,"chroma":0,"tone":100}"#).unwrap();
+    assert_eq!(opaque.argb(), 0xffffffff);
+}
+
+#[test]
+fn colors_serialize_as_hex_by_attribute() {
+    let theme = Theme {
+        seed: 0xff4285f4,
+        accent: HCT::from(0x80ff0000),
+        overlay: OkLch::from(0xff000000),
+    };
+    let json = serde_json::to_string(&theme).unwrap();
+    assert_eq!(
+        json,
+        r##"{"seed":"#4285f4","accent":"#ff000080","overlay":"#000000"}"##
+    );
+    let back: Theme =
+        serde_json::from_str(r##"{"seed":"#fff","accent":"ff000080","overlay":"#000000"}"##)
+            .unwrap();
+    assert_eq!(back.seed, 0xffffffff);
+    assert_eq!(back.accent.argb(), 0x80ff0000);
+    let invalid = r##"{"seed":"nope","accent":"#fff","overlay":"#fff"}"##;
+    assert!(serde_json::from_str::<Theme>(invalid).is_err());
+}
+
+#[test]
+fn tonal_palette_round_trips() {
+    let palette = TonalPalette::from(0x664285f4);
+    let json = serde_json::to_string(&palette).unwrap();
+    assert!(json.contains("\"keyColor\":"));
+    let back: TonalPalette = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.tone(40.0), palette.tone(40.0));
+}
+
+#[test]
+fn scheme_round_trips_with_camel_case_roles() {
+    let scheme = Scheme::dark(0xff4285f4);
+    let json = scheme.json().unwrap();
+    assert!(json.contains("\"onPrimaryContainer\":"));
+    let back: Scheme = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, scheme);
+}
+
+#[test]
+fn audit_enums_use_camel_case() {
+    assert_eq!(serde_json::to_string(&Level::AA).unwrap(), "\"aa\"");
+    assert_eq!(serde_json::to_string(&Level::AAA).unwrap(), "\"aaa\"");
+    assert_eq!(serde_json::from_str::<Level>("\"aaa\"").unwrap(), Level::AAA);
+    assert_eq!(serde_json::to_string(&Usage::NonText).unwrap(), "\"nonText\"");
+}
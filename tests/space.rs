@@ -0,0 +1,86 @@
+mod common;
+
+use material_rs::{
+    hct::HCT,
+    scheme::Scheme,
+    space::{
+        lab::{Lab, Lch},
+        oklab::{OkLab, OkLch},
+        xyz::{LinearRgb, Xyz},
+    },
+};
+
+use common::assert_close;
+
+#[test]
+fn lab_from_argb() {
+    let red: Lab = 0xffff0000.into();
+    assert_close(red.l, 53.233, 0.01);
+    assert_close(red.a, 80.091, 0.01);
+    assert_close(red.b, 67.203, 0.01);
+
+    let white: Lab = 0xffffffff.into();
+    assert_close(white.l, 100.0, 0.01);
+    assert_close(white.a, 0.0, 0.01);
+    assert_close(white.b, 0.0, 0.01);
+}
+
+#[test]
+fn color_spaces_round_trip_argb() {
+    for argb in [0xff000000, 0xffffffff, 0xff4285f4, 0xffea4335, 0xff34a853, 0xfffbbc05] {
+        assert_eq!(u32::from(Lab::from(argb)), argb);
+        assert_eq!(u32::from(Lch::from(argb)), argb);
+        assert_eq!(u32::from(Xyz::from(argb)), argb);
+        assert_eq!(u32::from(LinearRgb::from(argb)), argb);
+        assert_eq!(u32::from(Lab::from(Xyz::from(LinearRgb::from(argb)))), argb);
+    }
+}
+
+#[test]
+fn lch_from_lab() {
+    let lch: Lch = Lab::new(50.0, 0.0, 20.0).into();
+    assert_close(lch.c, 20.0, 1e-9);
+    assert_close(lch.h, 90.0, 1e-9);
+}
+
+#[test]
+fn hct_tone_matches_lab_lightness() {
+    let hct = HCT::new(220.0, 30.0, 60.0);
+    let lab: Lab = hct.into();
+    assert_close(lab.l, hct.tone(), 1e-9);
+    let back: HCT = lab.into();
+    assert_eq!(back.argb(), hct.argb());
+}
+
+#[test]
+fn oklab_from_argb() {
+    let white: OkLab = 0xffffffff.into();
+    assert_close(white.l, 1.0, 1e-4);
+    assert_close(white.a, 0.0, 1e-4);
+    assert_close(white.b, 0.0, 1e-4);
+
+    let red: OkLch = 0xffff0000.into();
+    assert_close(red.l, 0.6280, 1e-4);
+    assert_close(red.c, 0.2577, 1e-4);
+    assert_close(red.h, 29.23, 0.01);
+    assert_eq!(red.to_string(), "oklch(62.80% 0.2577 29.23)");
+}
+
+#[test]
+fn oklab_round_trips_argb_and_hct() {
+    for argb in [0xff000000, 0xffffffff, 0xff4285f4, 0xffea4335, 0xff34a853, 0xfffbbc05] {
+        assert_eq!(u32::from(OkLab::from(argb)), argb);
+        assert_eq!(u32::from(OkLch::from(argb)), argb);
+        let hct: HCT = argb.into();
+        assert_eq!(HCT::from(OkLch::from(hct)).argb(), argb);
+    }
+}
+
+#[test]
+fn scheme_emits_oklch() {
+    let scheme = Scheme::light(0xff4285f4);
+    let oklch = scheme.oklch();
+    assert_eq!(oklch.len(), scheme.roles().len());
+    assert_eq!(oklch[0].0, "primary");
+    assert!(oklch.iter().all(|(_, css)| css.starts_with("oklch(")));
+}
@@ -0,0 +1,20 @@
+use material_rs::utils::string::{
+    argb_from_hex, escape_double_quoted, hex_from_argb, hex_with_alpha_from_argb,
+};
+
+#[test]
+fn hex_round_trips() {
+    assert_eq!(hex_from_argb(0xff4285f4), "#4285f4");
+    assert_eq!(hex_with_alpha_from_argb(0x804285f4), "#4285f480");
+    assert_eq!(argb_from_hex("#4285f4"), Some(0xff4285f4));
+    assert_eq!(argb_from_hex("4285f480"), Some(0x804285f4));
+    assert_eq!(argb_from_hex("#fa0"), Some(0xffffaa00));
+    assert_eq!(argb_from_hex("#+12345"), None);
+    assert_eq!(argb_from_hex("#12345"), None);
+}
+
+#[test]
+fn escapes_double_quoted_strings() {
+    assert_eq!(escape_double_quoted("Material"), "Material");
+    assert_eq!(escape_double_quoted("a \"b\" \\ c\n"), "a \\\"b\\\" \\\\ c\\u000a");
+}
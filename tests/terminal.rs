@@ -0,0 +1,75 @@
+use material_rs::{
+    contrast::ratio_of_argb,
+    hct::HCT,
+    terminal::{xterm_256, Ansi256, TerminalColors},
+    utils::math::difference_degrees,
+};
+
+#[test]
+fn ansi_colors_are_readable_and_keep_their_hues() {
+    for colors in [TerminalColors::dark(0xff4285f4), TerminalColors::light(0xff4285f4)] {
+        for argb in &colors.ansi[1..7] {
+            assert!(ratio_of_argb(*argb, colors.background) >= 4.5);
+        }
+        assert!(ratio_of_argb(colors.foreground, colors.background) >= 7.0);
+        for (i, canonical) in [0xffff0000u32, 0xff00ff00, 0xffffff00, 0xff0000ff].iter().enumerate() {
+            let hue = HCT::from(colors.ansi[i + 1]).hue();
+            assert!(difference_degrees(hue, HCT::from(*canonical).hue()) <= 20.0);
+        }
+    }
+}
+
+#[test]
+fn ansi_256_follows_the_theme() {
+    let colors = TerminalColors::dark(0xff4285f4);
+    let palette = colors.ansi_256();
+    assert_eq!(palette[..16], colors.ansi);
+    assert_eq!(palette[16], colors.background);
+    assert_eq!(palette[231], colors.foreground);
+    assert_eq!(palette[16 + 36 * 5], colors.ansi[1]);
+    assert_eq!(palette[16 + 5], colors.ansi[4]);
+    let grays: Vec<f64> = palette[232..].iter().map(|argb| HCT::from(*argb).tone()).collect();
+    assert!(grays.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn exports_every_format() {
+    let colors = TerminalColors::dark(0xff4285f4);
+    let hex = |argb: u32| format!("#{:06x}", argb & 0xffffff);
+
+    let alacritty = colors.alacritty();
+    assert!(alacritty.contains(&format!("[colors.primary]\nbackground = \"{}\"", hex(colors.background))));
+    assert!(alacritty.contains(&format!("[colors.bright]\nblack = \"{}\"", hex(colors.ansi[8]))));
+
+    let kitty = colors.kitty();
+    assert!(kitty.contains(&format!("color15 {}\n", hex(colors.ansi[15]))));
+
+    let wezterm = colors.wezterm();
+    assert!(wezterm.starts_with("return {\n"));
+    assert!(wezterm.contains(&format!("ansi = {{ \"{}\", ", hex(colors.ansi[0]))));
+
+    let windows = colors.windows_terminal("Material \"Dark\"");
+    assert!(windows.contains("\"name\": \"Material \\\"Dark\\\"\""));
+    assert!(windows.contains(&format!("\"brightPurple\": \"{}\"", hex(colors.ansi[13]))));
+    assert!(windows.trim_end().ends_with("\"\n}"));
+
+    let xresources = colors.xresources();
+    assert!(xresources.contains(&format!("*.color1: {}\n", hex(colors.ansi[1]))));
+}
+
+#[test]
+fn ansi_256_matches_exact_and_near_colors() {
+    let xterm = xterm_256();
+    assert_eq!(xterm[196], 0xffff0000);
+    assert_eq!(xterm[232], 0xff080808);
+    let ansi = Ansi256::xterm();
+    for index in [21u8, 46, 196, 244] {
+        assert_eq!(ansi.nearest(xterm[index as usize]), index);
+    }
+    assert_eq!(ansi.nearest(0xfffe0101), 196);
+    assert!(ansi.nearest(0xff000000) >= 16);
+
+    let colors = TerminalColors::dark(0xff4285f4);
+    let themed = Ansi256::new(&colors.ansi_256());
+    assert_eq!(themed.nearest(colors.ansi_256()[100]), 100);
+}
@@ -0,0 +1,85 @@
+#![cfg(any(feature = "egui", feature = "iced", feature = "bevy", feature = "ratatui", feature = "crossterm"))]
+
+use material_rs::{scheme::Scheme, space::rgb::Rgb};
+
+fn rgb(argb: u32) -> (u8, u8, u8) {
+    let Rgb { r, g, b } = Rgb::<u8>::from(argb);
+    (r, g, b)
+}
+
+#[cfg(feature = "egui")]
+#[test]
+fn egui_visuals_follow_scheme() {
+    let scheme = Scheme::dark(0xff4285f4);
+    let visuals = egui::Visuals::from(&scheme);
+    assert!(visuals.dark_mode);
+    let (r, g, b) = rgb(scheme.surface());
+    assert_eq!(visuals.panel_fill, egui::Color32::from_rgb(r, g, b));
+    let (r, g, b) = rgb(scheme.error());
+    assert_eq!(visuals.error_fg_color, egui::Color32::from_rgb(r, g, b));
+    assert_ne!(visuals.widgets.hovered.bg_fill, visuals.widgets.inactive.bg_fill);
+    assert!(!egui::Visuals::from(&Scheme::light(0xff4285f4)).dark_mode);
+}
+
+#[cfg(feature = "iced")]
+#[test]
+fn iced_palette_follows_scheme() {
+    let scheme = Scheme::light(0xff4285f4);
+    let palette = iced_core::theme::Palette::from(&scheme);
+    let (r, g, b) = rgb(scheme.primary());
+    assert_eq!(palette.primary, iced_core::Color::from_rgb8(r, g, b));
+    assert_ne!(palette.success, palette.warning);
+    let theme = iced_core::Theme::from(&scheme);
+    assert_eq!(theme.to_string(), "Material");
+    assert_eq!(theme.palette(), palette);
+}
+
+#[cfg(feature = "bevy")]
+#[test]
+fn bevy_scheme_colors_follow_scheme() {
+    use bevy_color::Color;
+    use material_rs::{hct::HCT, interop::bevy::SchemeColors};
+
+    let scheme = Scheme::light(0xff4285f4);
+    let colors = SchemeColors::from(&scheme);
+    let (r, g, b) = rgb(scheme.primary());
+    assert_eq!(colors.primary, Color::srgb_u8(r, g, b));
+    assert_eq!(Color::from(HCT::from(0x80000000)), Color::srgba_u8(0, 0, 0, 0x80));
+}
+
+#[cfg(feature = "ratatui")]
+#[test]
+fn ratatui_styles_follow_scheme() {
+    use material_rs::{
+        interop::ratatui::{roles, SchemeStyles},
+        terminal::Ansi256,
+    };
+    use ratatui::style::Color;
+
+    let scheme = Scheme::dark(0xff4285f4);
+    let styles = SchemeStyles::from(&scheme);
+    let (r, g, b) = rgb(scheme.primary());
+    assert_eq!(styles.primary.bg, Some(Color::Rgb(r, g, b)));
+    assert_eq!(styles.outline.bg, styles.surface.bg);
+    assert_eq!(roles(&scheme)[0], ("primary", Color::Rgb(r, g, b)));
+
+    let indexed = SchemeStyles::indexed(&scheme, &Ansi256::xterm());
+    assert!(matches!(indexed.error.bg, Some(Color::Indexed(i)) if i >= 16));
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn crossterm_colors_follow_scheme() {
+    use crossterm::style::Color;
+    use material_rs::{
+        hct::HCT,
+        interop::crossterm::{ansi_value, rgb as color},
+        terminal::Ansi256,
+    };
+
+    let scheme = Scheme::dark(0xff4285f4);
+    let (r, g, b) = rgb(scheme.primary());
+    assert_eq!(color(scheme.primary()), Color::Rgb { r, g, b });
+    assert_eq!(Color::from(HCT::from(0x80ff0000)), Color::Rgb { r: 255, g: 0, b: 0 });
+    assert_eq!(ansi_value(0xffff0000, &Ansi256::xterm()), Color::AnsiValue(196));
+}